@@ -0,0 +1,81 @@
+// Compares the batch functions against the per element loops they replace, the loops are what the compiler
+// vectorizes without help so a ratio near 1 means the batch function adds nothing. Run with
+//
+//     cargo bench --bench batch
+//
+// and optionally `--features simd`. Each case reports the best of several runs over 64k vectors, the ratio is the
+// loop time divided by the batch time
+extern crate rmath;
+
+use std::hint::black_box;
+use std::time::{ Duration, Instant };
+
+use rmath::{ batch, Matrix4x4, Quaternion, Vector3 };
+
+const COUNT: usize = 64 * 1024;
+const RUNS: usize = 50;
+
+fn best_of<F: FnMut()>(mut f: F) -> Duration {
+    (0..RUNS).map(|_| {
+        let start = Instant::now();
+        f();
+        start.elapsed()
+    }).min().unwrap()
+}
+
+fn report(name: &str, looped: Duration, batched: Duration) {
+    let per_element = |d: Duration| d.as_secs_f64() * 1e9 / COUNT as f64;
+
+    println!("{:<20} loop {:>6.2} ns  batch {:>6.2} ns  {:.2}x", name, per_element(looped), per_element(batched),
+        looped.as_secs_f64() / batched.as_secs_f64());
+}
+
+fn main() {
+    let points: Vec<Vector3> = (0..COUNT).map(|i| {
+        let i = i as f32;
+        Vector3::new((i * 0.37).sin() * 10.0, (i * 0.11).cos() * 5.0, i * 0.001)
+    }).collect();
+    let q = Quaternion::from_euler(Vector3::new(0.3, -1.2, 0.8));
+    let m = Matrix4x4::make_from_trs(Vector3::new(1.0, -2.0, 3.0), q, Vector3::new(2.0, 0.5, 1.5));
+    let mut out = vec![Vector3::ZERO; COUNT];
+
+    let looped = best_of(|| {
+        let q = black_box(q);
+        for (p, o) in points.iter().zip(out.iter_mut()) {
+            *o = q * *p;
+        }
+        black_box(&out);
+    });
+    let batched = best_of(|| {
+        batch::rotate(black_box(q), &points, &mut out);
+        black_box(&out);
+    });
+    report("rotate", looped, batched);
+
+    let looped = best_of(|| {
+        let m = black_box(&m);
+        for (p, o) in points.iter().zip(out.iter_mut()) {
+            *o = m.transform_point(*p);
+        }
+        black_box(&out);
+    });
+    let batched = best_of(|| {
+        batch::transform_points(black_box(&m), &points, &mut out);
+        black_box(&out);
+    });
+    report("transform_points", looped, batched);
+
+    let looped = best_of(|| {
+        out.copy_from_slice(&points);
+        for v in out.iter_mut() {
+            v.normalize();
+        }
+        black_box(&out);
+    });
+    let batched = best_of(|| {
+        out.copy_from_slice(&points);
+        batch::normalize(&mut out);
+        black_box(&out);
+    });
+    report("normalize", looped, batched);
+}
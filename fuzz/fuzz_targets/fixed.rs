@@ -0,0 +1,133 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate arbitrary;
+extern crate rmath;
+extern crate rmath_fuzz;
+
+use arbitrary::{ Result, Unstructured };
+use rmath::{ Clamp, Clamp01, Fixed32, Fixed64, FixedQuaternion, FixedVector3, Quaternion, Vector3 };
+use rmath_fuzz::*;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = run(&mut Unstructured::new(data));
+});
+
+fn run(u: &mut Unstructured) -> Result<()> {
+    conversions(scalar(u)?, vector3(u)?, quaternion(u)?);
+    fixed32_ops(fixed32(u)?, fixed32(u)?, fixed32(u)?);
+    fixed64_ops(fixed64(u)?, fixed64(u)?, fixed64(u)?);
+
+    let (a, b) = (fixed_vector3(u)?, fixed_vector3(u)?);
+    let (p, q) = (fixed_quaternion(u)?, fixed_quaternion(u)?);
+    vector_ops(a, b, fixed64(u)?);
+    quaternion_ops(p, q, a, fixed64(u)?);
+
+    Ok(())
+}
+
+fn fixed_vector3(u: &mut Unstructured) -> Result<FixedVector3> {
+    Ok(FixedVector3::new(fixed64(u)?, fixed64(u)?, fixed64(u)?))
+}
+
+fn fixed_quaternion(u: &mut Unstructured) -> Result<FixedQuaternion> {
+    Ok(FixedQuaternion::new(fixed64(u)?, fixed64(u)?, fixed64(u)?, fixed64(u)?))
+}
+
+// Float to fixed conversion saturates and maps NaN to zero, so any float is accepted
+fn conversions(x: f32, v: Vector3, q: Quaternion) {
+    let a = Fixed32::from_f32(x);
+    let b = Fixed64::from_f64(x as f64);
+
+    if x.is_nan() {
+        assert_eq!(a, Fixed32::ZERO);
+        assert_eq!(b, Fixed64::ZERO);
+    }
+
+    assert!(a.to_f32().is_finite() && b.to_f64().is_finite());
+
+    let _ = (FixedVector3::from(v), FixedQuaternion::from(q));
+    let _ = (Vector3::from(FixedVector3::from(v)), Quaternion::from(FixedQuaternion::from(q)));
+}
+
+macro_rules! fixed_ops {
+    ($name:ident, $t:ident) => {
+        fn $name(a: $t, b: $t, t: $t) {
+            let _ = (a + b, a - b, a * b, -a, a.wrapping_add(b), a.wrapping_sub(b), a.wrapping_mul(b), a.wrapping_neg());
+            let _ = (a.saturating_add(b), a.saturating_sub(b), a.saturating_mul(b), a.saturating_neg());
+            let _ = (a.abs(), a.signum(), a.floor(), a.ceil(), a.round(), a.fract(), a.min(b), a.max(b));
+            let _ = ($t::lerp(a, b, t), $t::lerp_unclamped(a, b, t), a.sqrt(), a.sin(), a.cos(), a.tan());
+            let _ = (a.sin_cos(), $t::atan2(a, b), a.atan(), a.asin(), a.acos(), a.to_int(), a.to_f32(), a.to_f64());
+            let _ = (Clamp::clamp(a, b, t), a.clamp01(), a.exp(), a.ln());
+
+            // Division by zero panics like integer division, checked_div is the non-panicking form
+            match a.checked_div(b) {
+                Some(q) => assert!(b != $t::ZERO && q == a.wrapping_div(b)),
+                None => assert!(b == $t::ZERO || a.saturating_div(b) == $t::MAX || a.saturating_div(b) == $t::MIN)
+            }
+
+            if b != $t::ZERO {
+                let _ = (a / b, a.saturating_div(b));
+            }
+
+            let mut c = a;
+            c += b;
+            c -= b;
+            c *= b;
+        }
+    };
+}
+
+fixed_ops!(fixed32_ops, Fixed32);
+fixed_ops!(fixed64_ops, Fixed64);
+
+fn vector_ops(a: FixedVector3, b: FixedVector3, t: Fixed64) {
+    let _ = (a + b, a - b, a * b, a * t, t * a, -a, a + t, a - t);
+    let _ = (a.clamp_magnitude(t), a.sqr_magnitude(), a.magnitude(), a.normalized(), FixedVector3::dot(a, b));
+    let _ = (FixedVector3::cross(a, b), FixedVector3::distance(a, b), FixedVector3::angle(a, b));
+    let _ = (FixedVector3::scale(a, b), FixedVector3::min(a, b), FixedVector3::max(a, b), a.abs(), a.floor());
+    let _ = (a.ceil(), a.round(), a.signum(), a.min_element(), a.max_element(), FixedVector3::lerp(a, b, t));
+    let _ = (FixedVector3::lerp_unclamped(a, b, t), FixedVector3::move_towards(a, b, t));
+    let _ = (FixedVector3::project(a, b), FixedVector3::project_on_plane(a, b), FixedVector3::reflect(a, b));
+    let _ = (FixedVector3::rotate_towards(a, b, t, t), a[0], <[Fixed64; 3]>::from(a));
+    let _ = Clamp::clamp(a, b, a);
+
+    if t != Fixed64::ZERO {
+        let _ = a / t;
+    }
+
+    if b.x != Fixed64::ZERO && b.y != Fixed64::ZERO && b.z != Fixed64::ZERO {
+        let _ = a / b;
+    }
+
+    let mut c = a;
+    c += b;
+    c -= b;
+    c *= t;
+    c.normalize();
+}
+
+fn quaternion_ops(p: FixedQuaternion, q: FixedQuaternion, v: FixedVector3, t: Fixed64) {
+    let _ = (FixedQuaternion::from_angle_axis(t, v), FixedQuaternion::from_euler(v));
+    let _ = (FixedQuaternion::from_euler_components(v.x, v.y, v.z), p.forward(), p.right(), p.up());
+    let _ = (FixedQuaternion::dot(p, q), FixedQuaternion::lerp(p, q, t), FixedQuaternion::lerp_unclamped(p, q, t));
+    let _ = (FixedQuaternion::slerp(p, q, t), FixedQuaternion::slerp_unclamped(p, q, t));
+    let _ = (FixedQuaternion::angle_between(p, q), p.conjugate(), p.magnitude(), p.sqr_magnitude(), p.normalized());
+    let _ = (p + q, p - q, -p, p * q, p * v, p * t);
+    let _ = (FixedQuaternion::from_direction(v), FixedQuaternion::from_orientation(v, q * v));
+    let _ = (FixedQuaternion::look_rotation(v, p * v), FixedQuaternion::from_to_rotation(v, p * v), p.to_euler());
+    let _ = (FixedQuaternion::rotate_towards(p, q, t), p.exp(), p.ln(), p.pow(t), p.derivative(v), p.integrate(v, t));
+    let _ = (FixedQuaternion::squad(p, q, p, q, t), FixedQuaternion::squad_tangent(p, q, p), p.integrate_exact(v, t));
+    let _ = FixedQuaternion::angular_velocity(p, q, t);
+
+    // Zero and overflowing inverses fall back to the identity instead of dividing by zero
+    let _ = p.inverse();
+    assert_eq!(p.checked_inverse().is_some(), p.try_inverse().is_ok());
+
+    let mut angle = Fixed64::ZERO;
+    let mut axis = FixedVector3::ZERO;
+    p.to_angle_axis(&mut angle, &mut axis);
+
+    let mut r = p;
+    r.normalize();
+}
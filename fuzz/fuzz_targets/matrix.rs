@@ -0,0 +1,125 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate arbitrary;
+extern crate rmath;
+extern crate rmath_fuzz;
+
+use arbitrary::{ Result, Unstructured };
+use rmath::{ Affine3, Matrix3x2, Matrix3x3, Matrix4x4, Quaternion, Vector2, Vector3, Vector4 };
+use rmath::batch;
+use rmath_fuzz::*;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = run(&mut Unstructured::new(data));
+});
+
+fn run(u: &mut Unstructured) -> Result<()> {
+    let (v, w) = (vector3(u)?, vector3(u)?);
+    let q = quaternion(u)?;
+    let (a, b) = (matrix4x4(u)?, matrix4x4(u)?);
+
+    matrix3x2(vector2(u)?, vector2(u)?, scalar(u)?, scalar(u)?);
+    matrix3x3(v, w, q);
+    matrix4x4_ops(a, b, v, w, q, vector4(u)?);
+    affine3(a, v, w, q);
+    batches(a, b, q, &[v, w, vector3(u)?]);
+
+    Ok(())
+}
+
+fn matrix3x2(v: Vector2, w: Vector2, s: f32, t: f32) {
+    let a = Matrix3x2::new(v, w, Vector2::new(s, t));
+    let b = Matrix3x2::make_from_trs(v, s, w);
+
+    let _ = (Matrix3x2::make_from_translation(v), Matrix3x2::make_from_rotation(s), Matrix3x2::make_from_scale(v));
+    let _ = (Matrix3x2::make_from_skew(s, t), a.column(2), a.translation(), a.determinant(), a.inverse());
+    let _ = (a.transform_point(v), a.transform_vector(v), a.to_matrix4x4(), a * b, a * v);
+
+    let mut c = a;
+    c *= b;
+}
+
+fn matrix3x3(v: Vector3, w: Vector3, q: Quaternion) {
+    let a = Matrix3x3::new(v, w, Vector3::cross(v, w));
+    let b = Matrix3x3::make_from_rotation_scale(q, v);
+
+    let _ = (Matrix3x3::make_from_scale(v), Matrix3x3::make_from_rotation(q), a.column(1), a.row(2));
+    let _ = (a.transpose(), a.determinant(), a.inverse(), a * b, a * v);
+
+    let mut c = a;
+    c *= b;
+}
+
+fn matrix4x4_ops(a: Matrix4x4, b: Matrix4x4, v: Vector3, w: Vector3, q: Quaternion, p: Vector4) {
+    let trs = Matrix4x4::make_from_trs(v, q, w);
+
+    let _ = (Matrix4x4::make_from_translation(v), Matrix4x4::make_from_scale(v), Matrix4x4::make_from_rotation(q));
+    let _ = (a.column(3), a.row(3), a.transpose(), a.determinant(), a.inverse(), a.is_finite());
+    let _ = (a.transform_point(v), a.transform_direction(v), a * b, a * p, Matrix4x4::from_slice(a.as_ref()));
+
+    let mut c = a;
+    c *= b;
+
+    // try_inverse only hands out finite matrices
+    if let Ok(inverse) = a.try_inverse() {
+        assert_finite(inverse.as_ref(), "Matrix4x4::try_inverse");
+    }
+
+    if let Ok(inverse) = trs.try_inverse() {
+        assert_finite(inverse.as_ref(), "Matrix4x4::try_inverse");
+    }
+
+    if in_range(v.as_ref()) && in_range(w.as_ref()) && in_range(q.as_ref()) {
+        assert_finite(trs.as_ref(), "Matrix4x4::make_from_trs");
+    }
+}
+
+fn affine3(m: Matrix4x4, v: Vector3, w: Vector3, q: Quaternion) {
+    let a = Affine3::from_matrix(m);
+    let affine_row = m.m30 == 0.0 && m.m31 == 0.0 && m.m32 == 0.0 && m.m33 == 1.0;
+    assert_eq!(Affine3::try_from_matrix(m).is_ok(), affine_row && m.is_finite());
+    let b = Affine3::from_trs(v, q, w);
+
+    let _ = (Affine3::new(Matrix3x3::make_from_scale(v), w), Affine3::from_translation(v), Affine3::from_rotation(q));
+    let _ = (Affine3::from_scale(v), a.to_matrix(), a.transform_point(v), a.transform_direction(v));
+    let _ = (a.normal_matrix(), a.determinant(), a.inverse(), a * b, a * v);
+
+    let mut c = a;
+    c *= b;
+}
+
+// The slice functions agree with the per element operations, up to the sign of zero
+fn batches(a: Matrix4x4, b: Matrix4x4, q: Quaternion, vectors: &[Vector3]) {
+    let mut out = [Vector3::ZERO; 3];
+
+    batch::transform_points(&a, vectors, &mut out);
+    for (v, out) in vectors.iter().zip(out.iter()) {
+        assert_same(a.transform_point(*v), *out);
+    }
+
+    batch::transform_directions(&a, vectors, &mut out);
+    for (v, out) in vectors.iter().zip(out.iter()) {
+        assert_same(a.transform_direction(*v), *out);
+    }
+
+    batch::rotate(q, vectors, &mut out);
+
+    let mut normalized = [vectors[0], vectors[1], vectors[2]];
+    batch::normalize(&mut normalized);
+    for (v, out) in vectors.iter().zip(normalized.iter()) {
+        assert_same(v.normalized(), *out);
+    }
+
+    let _ = batch::bounds(vectors);
+
+    let mut matrices = [Matrix4x4::IDENTITY; 2];
+    batch::multiply_matrices(&[a, b], &[b, a], &mut matrices);
+    batch::premultiply_matrices(&a, &[a, b], &mut matrices);
+}
+
+fn assert_same(a: Vector3, b: Vector3) {
+    let same = a.as_ref().iter().zip(b.as_ref()).all(|(a, b)| a == b || (a.is_nan() && b.is_nan()));
+
+    assert!(same, "{:?} != {:?}", a, b);
+}
@@ -0,0 +1,136 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate arbitrary;
+extern crate rmath;
+extern crate rmath_fuzz;
+
+use arbitrary::{ Result, Unstructured };
+use rmath::{ Quaternion, DualQuaternion, Vector3 };
+use rmath::consts::PI;
+use rmath_fuzz::*;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = run(&mut Unstructured::new(data));
+});
+
+fn run(u: &mut Unstructured) -> Result<()> {
+    let (a, b, c, d) = (quaternion(u)?, quaternion(u)?, quaternion(u)?, quaternion(u)?);
+    let (v, w) = (vector3(u)?, vector3(u)?);
+    let t = scalar(u)?;
+
+    constructors(v, w, t);
+    operations(a, b, c, d, v, t);
+    dual_quaternion(a, b, v, w, t);
+
+    Ok(())
+}
+
+fn constructors(v: Vector3, w: Vector3, t: f32) {
+    let _ = (Quaternion::from_euler(v), Quaternion::from_euler_components(v.x, v.y, v.z), Quaternion::from_angle_axis(t, v));
+
+    let direction = Quaternion::from_direction(v);
+    let orientation = Quaternion::from_orientation(v, w);
+    let look = Quaternion::look_rotation(v, w);
+    let arc = Quaternion::from_to_rotation(v, w);
+
+    // Degenerate directions fall back to a valid rotation instead of producing NaN
+    if in_range(v.as_ref()) && in_range(w.as_ref()) {
+        assert_unit_or(direction.as_ref(), &[], "Quaternion::from_direction");
+        assert_unit_or(orientation.as_ref(), &[], "Quaternion::from_orientation");
+        assert_unit_or(look.as_ref(), &[], "Quaternion::look_rotation");
+        assert_unit_or(arc.as_ref(), &[], "Quaternion::from_to_rotation");
+    }
+
+    if let Ok(q) = Quaternion::try_from_orientation(v, w) {
+        assert_unit_or(q.as_ref(), &[], "Quaternion::try_from_orientation");
+    }
+
+    if let Ok(q) = Quaternion::try_look_rotation(v, w) {
+        assert_unit_or(q.as_ref(), &[], "Quaternion::try_look_rotation");
+    }
+
+    if let Ok(q) = Quaternion::try_from_to_rotation(v, w) {
+        assert_unit_or(q.as_ref(), &[], "Quaternion::try_from_to_rotation");
+    }
+}
+
+fn operations(a: Quaternion, b: Quaternion, c: Quaternion, d: Quaternion, v: Vector3, t: f32) {
+    let _ = (a + b, a - b, a * b, a * v, a * t, a.rotate_unit(v), a.forward(), a.right(), a.up());
+    let _ = (a.to_euler(), Quaternion::dot(a, b), Quaternion::scale(a, t), a.conjugate(), a.magnitude());
+    let _ = (Quaternion::lerp(a, b, t), Quaternion::lerp_unclamped(a, b, t), Quaternion::slerp(a, b, t));
+    let _ = (Quaternion::slerp_unclamped(a, b, t), Quaternion::rotate_towards(a, b, t), a.exp(), a.ln(), a.pow(t));
+    let _ = (Quaternion::squad(a, b, c, d, t), Quaternion::squad_tangent(a, b, c), a.inverse(), a.sqr_magnitude());
+    let _ = (a.derivative(v), a.integrate(v, t), a.integrate_exact(v, t), Quaternion::angular_velocity(a, b, t));
+
+    let mut e = a;
+    e += b;
+    e -= b;
+    e *= b;
+    e *= t;
+    e.normalize();
+    e.normalize_or(b);
+
+    let mut angle = 0.0;
+    let mut axis = Vector3::ZERO;
+    a.to_angle_axis(&mut angle, &mut axis);
+
+    assert_unit_or(a.normalized_or(Quaternion::IDENTITY).as_ref(), &[], "Quaternion::normalized_or");
+    assert_unit_or(a.normalized().as_ref(), Quaternion::IDENTITY.as_ref(), "Quaternion::normalized");
+
+    if let Ok(n) = a.try_normalized() {
+        assert_unit_or(n.as_ref(), &[], "Quaternion::try_normalized");
+    }
+
+    if let Ok(inverse) = a.try_inverse() {
+        assert_finite(inverse.as_ref(), "Quaternion::try_inverse");
+    }
+
+    // The exponentials are only finite up to where f32 exp overflows, see the readme
+    if in_range(a.as_ref()) && in_range(&[t]) {
+        if a.w <= 88.0 {
+            assert_finite(a.exp().as_ref(), "Quaternion::exp");
+        }
+
+        if a.sqr_magnitude() > 0.0 {
+            let ln = a.ln();
+            assert_finite(ln.as_ref(), "Quaternion::ln");
+
+            if ln.w * t <= 88.0 {
+                assert_finite(a.pow(t).as_ref(), "Quaternion::pow");
+            }
+        }
+    }
+
+    if in_range(a.as_ref()) && in_range(b.as_ref()) {
+        let angle = Quaternion::angle_between(a, b);
+
+        assert!((0.0..=2.0 * PI + 1e-6).contains(&angle), "Quaternion::angle_between out of range: {}", angle);
+
+        // Interpolating between rotations stays a rotation
+        if in_range(&[t]) {
+            let (a, b) = (a.normalized(), b.normalized());
+
+            assert_unit_or(Quaternion::slerp(a, b, t).as_ref(), &[], "Quaternion::slerp");
+        }
+    }
+}
+
+fn dual_quaternion(a: Quaternion, b: Quaternion, v: Vector3, w: Vector3, t: f32) {
+    let p = DualQuaternion::new(a, b);
+    let q = DualQuaternion::from_rotation_translation(a, v);
+
+    let _ = (DualQuaternion::from_rotation(a), DualQuaternion::from_translation(v), p.rotation(), p.translation());
+    let _ = (p.transform_point(w), p.transform_direction(w), DualQuaternion::dot(p, q), p.conjugate(), p.inverse());
+    let _ = (p.magnitude(), DualQuaternion::sclerp(p, q, t), DualQuaternion::sclerp_unclamped(p, q, t));
+    let _ = (p + q, p * q, p * w, p * t, DualQuaternion::dlb(&[p, q], &[t, 1.0 - t]));
+
+    let mut r = p;
+    r += q;
+    r *= t;
+    r.normalize();
+
+    if in_range(a.as_ref()) && in_range(b.as_ref()) {
+        assert_unit_or(p.normalized().real.as_ref(), Quaternion::IDENTITY.as_ref(), "DualQuaternion::normalized");
+    }
+}
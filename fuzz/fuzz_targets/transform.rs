@@ -0,0 +1,69 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate arbitrary;
+extern crate rmath;
+extern crate rmath_fuzz;
+
+use arbitrary::{ Result, Unstructured };
+use rmath::{ DepthRange, Ray, Transform, Vector2, Vector3, Viewport, ViewportOrigin };
+use rmath_fuzz::*;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = run(&mut Unstructured::new(data));
+});
+
+fn run(u: &mut Unstructured) -> Result<()> {
+    let a = Transform::new(vector3(u)?, quaternion(u)?, vector3(u)?);
+    let b = Transform::new(vector3(u)?, quaternion(u)?, vector3(u)?);
+    let (v, t) = (vector3(u)?, scalar(u)?);
+
+    transform(a, b, v, t);
+    ray(v, vector3(u)?, t);
+
+    let mut viewport = Viewport::new(scalar(u)?, scalar(u)?, scalar(u)?, scalar(u)?);
+    viewport.min_depth = scalar(u)?;
+    viewport.max_depth = scalar(u)?;
+    viewport.depth_range = if u.arbitrary()? { DepthRange::ZeroToOne } else { DepthRange::NegativeOneToOne };
+    viewport.origin = if u.arbitrary()? { ViewportOrigin::TopLeft } else { ViewportOrigin::BottomLeft };
+
+    let view_projection = matrix4x4(u)?;
+    let _ = viewport.project(v, view_projection);
+
+    if let Some(p) = viewport.unproject(v, view_projection) {
+        assert!(p.is_finite(), "Viewport::unproject returned {:?}", p);
+    }
+
+    if let Some(r) = viewport.screen_point_to_ray(Vector2::new(v.x, v.y), view_projection) {
+        assert!(r.origin.is_finite() && r.direction.is_finite(), "Viewport::screen_point_to_ray");
+    }
+
+    Ok(())
+}
+
+fn transform(a: Transform, b: Transform, v: Vector3, t: f32) {
+    let _ = (Transform::from_position(v), Transform::from_rotation(a.rotation), Transform::from_scale(v));
+    let _ = (a.forward(), a.right(), a.up(), a.transform_point(v), a.transform_vector(v), a.transform_direction(v));
+    let _ = (a.inverse_transform_point(v), a.inverse_transform_vector(v), a.inverse_transform_direction(v));
+    let _ = (a.inverse(), Transform::lerp(a, b, t), a.to_matrix(), a * b, a * v);
+
+    let mut c = a;
+    c *= b;
+
+    // Zero scale components invert to zero instead of infinity
+    let unit = Transform::new(a.position, a.rotation.normalized(), a.scale);
+    if in_range(a.position.as_ref()) && in_range(a.scale.as_ref()) && in_range(a.rotation.as_ref()) && in_range(v.as_ref()) {
+        assert_finite(unit.inverse_transform_point(v).as_ref(), "Transform::inverse_transform_point");
+        assert_finite(unit.inverse().position.as_ref(), "Transform::inverse");
+        assert_unit_or(unit.inverse().rotation.as_ref(), &[], "Transform::inverse");
+    }
+}
+
+fn ray(origin: Vector3, direction: Vector3, t: f32) {
+    let r = Ray::new(origin, direction);
+    let _ = r.get_point(t);
+
+    if in_range(direction.as_ref()) {
+        assert_unit_or(r.direction.as_ref(), Vector3::ZERO.as_ref(), "Ray::new");
+    }
+}
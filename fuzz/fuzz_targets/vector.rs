@@ -0,0 +1,147 @@
+#![no_main]
+#[macro_use]
+extern crate libfuzzer_sys;
+extern crate arbitrary;
+extern crate rmath;
+extern crate rmath_fuzz;
+
+use arbitrary::{ Result, Unstructured };
+use rmath::{ Vector2, Vector3, Vector4, Vector3x4, Mask4, MathError };
+use rmath::consts::PI;
+use rmath_fuzz::*;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = run(&mut Unstructured::new(data));
+});
+
+fn run(u: &mut Unstructured) -> Result<()> {
+    vector2_ops(vector2(u)?, vector2(u)?, scalar(u)?);
+    vector3_ops(vector3(u)?, vector3(u)?, vector3(u)?, scalar(u)?, scalar(u)?);
+    vector4_ops(vector4(u)?, vector4(u)?, scalar(u)?);
+    wide_ops(u)?;
+
+    Ok(())
+}
+
+fn vector2_ops(a: Vector2, b: Vector2, t: f32) {
+    let _ = (a + b, a - b, a * b, a * t, t * a, a / t, -a, a + t, a - t);
+    let _ = (a.clamp_magnitude(t), a.sqr_magnitude(), a.magnitude(), a.perpendicular());
+    let _ = (Vector2::dot(a, b), Vector2::cross(a, b), Vector2::distance(a, b), Vector2::signed_angle(a, b));
+    let _ = (Vector2::scale(a, b), Vector2::lerp(a, b, t), Vector2::lerp_unclamped(a, b, t));
+    let _ = (Vector2::project(a, b), Vector2::reflect(a, b));
+
+    let mut c = a;
+    c += t;
+    c -= t;
+    c *= t;
+    c /= t;
+    c.normalize();
+
+    let angle = Vector2::angle(a, b);
+    if in_range(a.as_ref()) && in_range(b.as_ref()) {
+        assert_unit_or(a.normalized().as_ref(), Vector2::ZERO.as_ref(), "Vector2::normalized");
+        assert!((0.0..=PI + 1e-6).contains(&angle), "Vector2::angle out of range: {}", angle);
+    }
+}
+
+fn vector3_ops(a: Vector3, b: Vector3, c: Vector3, s: f32, t: f32) {
+    let _ = (a + b, a - b, a * b, a / b, a * t, t * a, a / t, -a, a + t, a - t);
+    let _ = (a.extend(t), a.clamp_magnitude(t), a.sqr_magnitude(), a.magnitude());
+    let _ = (Vector3::dot(a, b), Vector3::cross(a, b), Vector3::distance(a, b), Vector3::scale(a, b));
+    let _ = (Vector3::min(a, b), Vector3::max(a, b), a.abs(), a.floor(), a.ceil(), a.round(), a.signum(), a.recip());
+    let _ = (a.min_element(), a.max_element(), a.mul_add(b, c), a.is_finite(), a.is_nan());
+    let _ = (Vector3::lerp(a, b, t), Vector3::lerp_unclamped(a, b, t), Vector3::move_towards(a, b, t));
+    let _ = (Vector3::rotate_towards(a, b, s, t), Vector3::project(a, b), Vector3::project_on_segment(a, b, c));
+    let _ = (Vector3::project_on_plane(a, b), Vector3::reflect(a, b));
+
+    let mut d = a;
+    d += t;
+    d -= t;
+    d *= t;
+    d /= t;
+    d.normalize();
+    d.normalize_or(b);
+
+    let (mut e, mut f) = (a, b);
+    Vector3::ortho_normalize(&mut e, &mut f);
+
+    let normalized = a.normalized();
+    let angle = Vector3::angle(a, b);
+    assert_unit_or(a.normalized_or(Vector3::UP).as_ref(), &[], "Vector3::normalized_or");
+
+    match a.try_normalized() {
+        Ok(n) => assert_unit_or(n.as_ref(), &[], "Vector3::try_normalized"),
+        Err(MathError::ZeroLength) => assert!(a.magnitude() <= 1.2e-7, "{:?} is not short", a),
+        Err(MathError::NonFinite) => assert!(!a.is_finite()),
+        Err(e) => panic!("unexpected error {:?}", e)
+    }
+
+    if in_range(a.as_ref()) && in_range(b.as_ref()) {
+        assert_unit_or(normalized.as_ref(), Vector3::ZERO.as_ref(), "Vector3::normalized");
+        assert!((0.0..=PI + 1e-6).contains(&angle), "Vector3::angle out of range: {}", angle);
+    }
+}
+
+fn vector4_ops(a: Vector4, b: Vector4, t: f32) {
+    let _ = (a + b, a - b, a * b, a / b, a * t, t * a, a / t, -a, a + t, a - t);
+    let _ = (Vector4::from_point(a.truncate()), Vector4::from_direction(a.truncate()), a.perspective_divide());
+    let _ = (a.clamp_magnitude(t), a.sqr_magnitude(), a.magnitude(), Vector4::dot(a, b), Vector4::distance(a, b));
+    let _ = (Vector4::scale(a, b), Vector4::min(a, b), Vector4::max(a, b), a.abs(), a.floor(), a.ceil());
+    let _ = (a.round(), a.signum(), a.recip(), a.min_element(), a.max_element(), a.mul_add(a, b));
+    let _ = (a.is_finite(), a.is_nan(), Vector4::lerp(a, b, t), Vector4::lerp_unclamped(a, b, t));
+    let _ = Vector4::project(a, b);
+
+    let mut c = a;
+    c += t;
+    c -= t;
+    c *= t;
+    c /= t;
+    c.normalize();
+    c.normalize_or(b);
+
+    if let Ok(n) = a.try_normalized() {
+        assert_unit_or(n.as_ref(), &[], "Vector4::try_normalized");
+    }
+
+    if in_range(a.as_ref()) {
+        assert_unit_or(a.normalized().as_ref(), Vector4::ZERO.as_ref(), "Vector4::normalized");
+    }
+
+    // A successful divide of a finite point never hands out infinities
+    if let (true, Some(p)) = (in_range(a.as_ref()), a.perspective_divide()) {
+        assert_finite(p.as_ref(), "Vector4::perspective_divide");
+    }
+}
+
+fn wide_ops(u: &mut Unstructured) -> Result<()> {
+    let lanes = [vector3(u)?, vector3(u)?, vector3(u)?, vector3(u)?];
+    let a = Vector3x4::from_slice(&lanes);
+    let b = Vector3x4::splat(vector3(u)?);
+    let t = scalar(u)?;
+
+    let _ = (a + b, a - b, a * b, a * t, a / t, -a, Vector3x4::dot(a, b), Vector3x4::cross(a, b));
+    let _ = (Vector3x4::distance(a, b), Vector3x4::min(a, b), Vector3x4::max(a, b), Vector3x4::lerp_unclamped(a, b, t));
+    let _ = (a.horizontal_sum(), a.horizontal_min(), a.horizontal_max());
+
+    let mask = Mask4::lt(a.x, b.x);
+    let _ = (mask.bitmask(), mask.any(), mask.all());
+
+    // select is a bitwise blend, each lane is exactly one of the inputs
+    let selected = Vector3x4::select(mask, a, b);
+    for i in 0..4 {
+        let expected = if mask.test(i) { a.x[i] } else { b.x[i] };
+        assert_eq!(selected.x[i].to_bits(), expected.to_bits());
+    }
+
+    // Every lane matches the scalar operation, including the zero fallback
+    let normalized = a.normalized();
+    for (i, v) in lanes.iter().enumerate() {
+        let lane = normalized.lane(i);
+        let expected = v.normalized();
+        let same = lane.as_ref().iter().zip(expected.as_ref()).all(|(a, b)| a == b || (a.is_nan() && b.is_nan()));
+
+        assert!(same, "{:?} != {:?}", lane, expected);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// Input generators and policy checks shared by the fuzz targets. The checks mirror the edge case policy in
+// the readme: nothing panics on any float, and finite inputs within RANGE produce finite results
+extern crate arbitrary;
+extern crate rmath;
+
+use arbitrary::{ Result, Unstructured };
+use rmath::{ Vector2, Vector3, Vector4, Quaternion, Matrix4x4, Fixed32, Fixed64 };
+
+// Largest magnitude the finiteness guarantees cover, beyond it intermediate products may overflow. Non-zero
+// values below 1 / RANGE are excluded as well since their reciprocals overflow just the same
+pub const RANGE: f32 = 1.0e6;
+
+// Special values are picked often enough that every branch sees zero, NaN and the infinities
+pub fn scalar(u: &mut Unstructured) -> Result<f32> {
+    Ok(match u.int_in_range(0u8..=15)? {
+        0 => 0.0,
+        1 => -0.0,
+        2 => f32::NAN,
+        3 => f32::INFINITY,
+        4 => f32::NEG_INFINITY,
+        5 => f32::EPSILON,
+        6 => f32::MIN_POSITIVE,
+        7 => f32::MAX,
+        8 => 1.0,
+        9 => -1.0,
+        10 | 11 => f32::from_bits(u.arbitrary()?),
+        _ => {
+            let unit = u.arbitrary::<i32>()? as f32 / i32::MAX as f32;
+
+            unit * 10.0f32.powi(u.int_in_range(-6..=6)?)
+        }
+    })
+}
+
+pub fn vector2(u: &mut Unstructured) -> Result<Vector2> {
+    Ok(Vector2::new(scalar(u)?, scalar(u)?))
+}
+
+pub fn vector3(u: &mut Unstructured) -> Result<Vector3> {
+    Ok(Vector3::new(scalar(u)?, scalar(u)?, scalar(u)?))
+}
+
+pub fn vector4(u: &mut Unstructured) -> Result<Vector4> {
+    Ok(Vector4::new(scalar(u)?, scalar(u)?, scalar(u)?, scalar(u)?))
+}
+
+pub fn quaternion(u: &mut Unstructured) -> Result<Quaternion> {
+    Ok(Quaternion::new(scalar(u)?, scalar(u)?, scalar(u)?, scalar(u)?))
+}
+
+pub fn matrix4x4(u: &mut Unstructured) -> Result<Matrix4x4> {
+    Ok(Matrix4x4::new(vector4(u)?, vector4(u)?, vector4(u)?, vector4(u)?))
+}
+
+pub fn fixed32(u: &mut Unstructured) -> Result<Fixed32> {
+    Ok(Fixed32::from_bits(u.arbitrary()?))
+}
+
+pub fn fixed64(u: &mut Unstructured) -> Result<Fixed64> {
+    Ok(Fixed64::from_bits(u.arbitrary()?))
+}
+
+// Zero or a finite magnitude within [1 / RANGE, RANGE]
+pub fn in_range(values: &[f32]) -> bool {
+    values.iter().all(|v| *v == 0.0 || (v.abs() >= 1.0 / RANGE && v.abs() <= RANGE))
+}
+
+pub fn assert_finite(values: &[f32], what: &str) {
+    assert!(values.iter().all(|v| v.is_finite()), "{} is not finite: {:?}", what, values);
+}
+
+// Normalizing either succeeds with unit length or falls back to the documented value
+pub fn assert_unit_or(values: &[f32], fallback: &[f32], what: &str) {
+    assert_finite(values, what);
+
+    if values != fallback {
+        let length = values.iter().map(|v| v * v).sum::<f32>().sqrt();
+        assert!((length - 1.0).abs() <= 1e-4, "{} is not unit length: {:?}", what, values);
+    }
+}
@@ -0,0 +1,154 @@
+use core::ops::{ Mul, MulAssign };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
+
+use { ApproxEq, MathError, Vector3, Quaternion, Matrix3x3, Matrix4x4 };
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Affine3 {
+    pub linear: Matrix3x3,
+    pub translation: Vector3
+}
+
+#[allow(dead_code)]
+impl Affine3 {
+    pub const IDENTITY: Affine3 = Affine3 {
+        linear: Matrix3x3::IDENTITY,
+        translation: Vector3::ZERO
+    };
+
+    pub fn new(linear: Matrix3x3, translation: Vector3) -> Affine3 {
+        Affine3 {
+            linear,
+            translation
+        }
+    }
+
+    pub fn from_translation(translation: Vector3) -> Affine3 {
+        Affine3::new(Matrix3x3::IDENTITY, translation)
+    }
+
+    pub fn from_rotation(rotation: Quaternion) -> Affine3 {
+        Affine3::new(Matrix3x3::make_from_rotation(rotation), Vector3::ZERO)
+    }
+
+    pub fn from_scale(scale: Vector3) -> Affine3 {
+        Affine3::new(Matrix3x3::make_from_scale(scale), Vector3::ZERO)
+    }
+
+    pub fn from_trs(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Affine3 {
+        Affine3::new(Matrix3x3::make_from_rotation_scale(rotation, scale), translation)
+    }
+
+    // Keeps the upper 3x4 block and silently drops the last row, so a projective matrix loses its projection.
+    // Lossless for any matrix whose last row is exactly (0, 0, 0, 1), try_from_matrix checks that first
+    pub fn from_matrix(m: Matrix4x4) -> Affine3 {
+        Affine3 {
+            linear: Matrix3x3 {
+                m00: m.m00, m01: m.m01, m02: m.m02,
+                m10: m.m10, m11: m.m11, m12: m.m12,
+                m20: m.m20, m21: m.m21, m22: m.m22
+            },
+            translation: Vector3::new(m.m03, m.m13, m.m23)
+        }
+    }
+
+    // Fails on non-finite input and when the last row is not exactly (0, 0, 0, 1), where from_matrix would lose
+    // information
+    pub fn try_from_matrix(m: Matrix4x4) -> Result<Affine3, MathError> {
+        if !m.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+
+        if m.m30 != 0.0 || m.m31 != 0.0 || m.m32 != 0.0 || m.m33 != 1.0 {
+            return Err(MathError::NotAffine);
+        }
+
+        Ok(Affine3::from_matrix(m))
+    }
+
+    pub fn to_matrix(&self) -> Matrix4x4 {
+        let mut m = Matrix4x4::from(self.linear);
+
+        m.m03 = self.translation.x;
+        m.m13 = self.translation.y;
+        m.m23 = self.translation.z;
+
+        m
+    }
+
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        self.linear * point + self.translation
+    }
+
+    pub fn transform_direction(&self, direction: Vector3) -> Vector3 {
+        self.linear * direction
+    }
+
+    // Transforms surface normals correctly under non-uniform scale, the result should be renormalized
+    pub fn normal_matrix(&self) -> Matrix3x3 {
+        self.linear.inverse().transpose()
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.linear.determinant()
+    }
+
+    // Only the 3x3 part needs a real inverse, singular linear parts produce non-finite values
+    pub fn inverse(&self) -> Affine3 {
+        let linear = self.linear.inverse();
+
+        Affine3 {
+            linear,
+            translation: -(linear * self.translation)
+        }
+    }
+}
+
+impl fmt::Debug for Affine3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(linear: {:?}, translation: {:?})", self.linear, self.translation)
+    }
+}
+
+impl fmt::Display for Affine3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(linear: {}, translation: {})", self.linear, self.translation)
+    }
+}
+
+impl PartialEq for Affine3 {
+    fn eq(&self, other: &Affine3) -> bool {
+        self.approx_eq(*other)
+    }
+}
+
+impl Eq for Affine3 {}
+
+impl_op! { ApproxEq,
+    fn approx_eq(self: Affine3, other: Affine3) -> bool {
+        self.linear.approx_eq(other.linear) && self.translation.approx_eq(other.translation)
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Affine3, other: Affine3) -> Affine3 {
+        Affine3 {
+            linear: self.linear * other.linear,
+            translation: self.linear * other.translation + self.translation
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Affine3, other: Vector3) -> Vector3 {
+        self.transform_point(other)
+    }
+}
+
+impl_op! { MulAssign,
+    fn mul_assign(&mut self: Affine3, other: Affine3) {
+        *self = *self * other;
+    }
+}
@@ -1,5 +1,3 @@
-use std;
-
 pub trait ApproxEq<Other = Self> where Other: ?Sized {
     type Output;
     
@@ -38,5 +36,5 @@ macro_rules! impl_approx_eq {
     }
 }
 
-impl_approx_eq!(f32, std::f32::EPSILON);
-impl_approx_eq!(f64, std::f64::EPSILON);
\ No newline at end of file
+impl_approx_eq!(f32, f32::EPSILON);
+impl_approx_eq!(f64, f64::EPSILON);
\ No newline at end of file
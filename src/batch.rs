@@ -0,0 +1,115 @@
+// Slice level operations. Anything that only depends on the transform is computed once up front, the per element
+// loops are plain scalar code that the compiler unrolls and vectorizes on its own. Packing the input into Vector3x8
+// chunks first was measured slower than that because of the transposition, see benches/batch.rs. Matrix products go
+// through the Matrix4x4 operator and pick up the SSE backend when the simd feature is enabled. Functions writing to
+// `out` panic if it is not the same length as the input
+use { Matrix4x4, Quaternion, Vector3 };
+
+// Row major 3x3 block plus translation, applied as m * v + t
+struct Linear {
+    m: [[f32; 3]; 3],
+    t: Vector3
+}
+
+impl Linear {
+    fn apply(&self, v: Vector3) -> Vector3 {
+        let m = &self.m;
+
+        Vector3 {
+            x: m[0][0] * v.x + m[0][1] * v.y + m[0][2] * v.z + self.t.x,
+            y: m[1][0] * v.x + m[1][1] * v.y + m[1][2] * v.z + self.t.y,
+            z: m[2][0] * v.x + m[2][1] * v.y + m[2][2] * v.z + self.t.z
+        }
+    }
+
+    fn map(&self, input: &[Vector3], out: &mut [Vector3]) {
+        assert_eq!(input.len(), out.len(), "batch output length does not match the input");
+
+        for (v, out) in input.iter().zip(out.iter_mut()) {
+            *out = self.apply(*v);
+        }
+    }
+}
+
+// Same as calling Matrix4x4::transform_point on every element
+pub fn transform_points(m: &Matrix4x4, points: &[Vector3], out: &mut [Vector3]) {
+    let linear = Linear {
+        m: [[m.m00, m.m01, m.m02], [m.m10, m.m11, m.m12], [m.m20, m.m21, m.m22]],
+        t: Vector3::new(m.m03, m.m13, m.m23)
+    };
+
+    linear.map(points, out);
+}
+
+// Same as calling Matrix4x4::transform_direction on every element
+pub fn transform_directions(m: &Matrix4x4, directions: &[Vector3], out: &mut [Vector3]) {
+    let linear = Linear {
+        m: [[m.m00, m.m01, m.m02], [m.m10, m.m11, m.m12], [m.m20, m.m21, m.m22]],
+        t: Vector3::ZERO
+    };
+
+    linear.map(directions, out);
+}
+
+// Rotates every element by `q`, equivalent to `q * v`. The quaternion is expanded into a matrix once so each
+// vector only costs nine multiplies, about 1.2x faster than a `q * v` loop in benches/batch.rs. The expansion
+// does not assume a unit quaternion, like `q * v` the result is scaled by the squared magnitude of `q`
+pub fn rotate(q: Quaternion, vectors: &[Vector3], out: &mut [Vector3]) {
+    let (xx, yy, zz, ww) = (q.x * q.x, q.y * q.y, q.z * q.z, q.w * q.w);
+    let (xy, xz, yz) = (2.0 * q.x * q.y, 2.0 * q.x * q.z, 2.0 * q.y * q.z);
+    let (wx, wy, wz) = (2.0 * q.w * q.x, 2.0 * q.w * q.y, 2.0 * q.w * q.z);
+
+    let linear = Linear {
+        m: [
+            [ww + xx - yy - zz, xy - wz, xz + wy],
+            [xy + wz, ww - xx + yy - zz, yz - wx],
+            [xz - wy, yz + wx, ww - xx - yy + zz]
+        ],
+        t: Vector3::ZERO
+    };
+
+    linear.map(vectors, out);
+}
+
+// Same as calling Vector3::normalize on every element
+pub fn normalize(vectors: &mut [Vector3]) {
+    for v in vectors.iter_mut() {
+        v.normalize();
+    }
+}
+
+// Component wise minimum and maximum of the points, None for an empty slice
+pub fn bounds(points: &[Vector3]) -> Option<(Vector3, Vector3)> {
+    let first = match points.first() {
+        Some(p) => *p,
+        None => return None
+    };
+
+    let mut min = first;
+    let mut max = first;
+    for p in points[1..].iter() {
+        min = Vector3::min(min, *p);
+        max = Vector3::max(max, *p);
+    }
+
+    Some((min, max))
+}
+
+// out[i] = a[i] * b[i], e.g. the skinning palette from world space bone matrices and inverse bind poses
+pub fn multiply_matrices(a: &[Matrix4x4], b: &[Matrix4x4], out: &mut [Matrix4x4]) {
+    assert_eq!(a.len(), b.len(), "batch input lengths do not match");
+    assert_eq!(a.len(), out.len(), "batch output length does not match the input");
+
+    for ((a, b), out) in a.iter().zip(b).zip(out.iter_mut()) {
+        *out = a * b;
+    }
+}
+
+// out[i] = m * matrices[i], e.g. moving a palette into another space
+pub fn premultiply_matrices(m: &Matrix4x4, matrices: &[Matrix4x4], out: &mut [Matrix4x4]) {
+    assert_eq!(matrices.len(), out.len(), "batch output length does not match the input");
+
+    for (b, out) in matrices.iter().zip(out.iter_mut()) {
+        *out = m * b;
+    }
+}
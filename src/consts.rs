@@ -1,5 +1,5 @@
-pub use std::f32::consts::PI;
-pub use std::f32::EPSILON;
+pub use core::f32::consts::PI;
+pub const EPSILON: f32 = f32::EPSILON;
 
 #[allow(dead_code)] pub const DEG2RAD: f32 = PI * 2.0 / 360.0;
 #[allow(dead_code)] pub const RAD2DEG: f32 = 1.0 / DEG2RAD;
\ No newline at end of file
@@ -0,0 +1,253 @@
+use core::ops::{ Add, Mul, AddAssign, MulAssign };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
+
+use { ApproxEq, Clamp01, Quaternion, Vector3 };
+use consts::{ EPSILON };
+use math;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DualQuaternion {
+    pub real: Quaternion,
+    pub dual: Quaternion
+}
+
+#[allow(dead_code)]
+impl DualQuaternion {
+    /*
+        Notes:
+        https://www.cs.utah.edu/~ladislav/kavan07skinning/kavan07skinning.pdf
+        https://www.cs.utah.edu/~ladislav/kavan06dual/kavan06dual.pdf
+    */
+
+    pub const IDENTITY: DualQuaternion = DualQuaternion {
+        real: Quaternion::IDENTITY,
+        dual: Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 0.0 }
+    };
+
+    pub fn new(real: Quaternion, dual: Quaternion) -> DualQuaternion {
+        DualQuaternion {
+            real,
+            dual
+        }
+    }
+
+    pub fn from_rotation_translation(rotation: Quaternion, translation: Vector3) -> DualQuaternion {
+        let t = Quaternion::new(translation.x, translation.y, translation.z, 0.0);
+
+        DualQuaternion {
+            real: rotation,
+            dual: t * rotation * 0.5
+        }
+    }
+
+    pub fn from_rotation(rotation: Quaternion) -> DualQuaternion {
+        DualQuaternion::from_rotation_translation(rotation, Vector3::ZERO)
+    }
+
+    pub fn from_translation(translation: Vector3) -> DualQuaternion {
+        DualQuaternion::from_rotation_translation(Quaternion::IDENTITY, translation)
+    }
+
+    pub fn rotation(&self) -> Quaternion {
+        self.real
+    }
+
+    pub fn translation(&self) -> Vector3 {
+        let t = self.dual * self.real.conjugate() * 2.0;
+
+        Vector3::new(t.x, t.y, t.z)
+    }
+
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        self.real * point + self.translation()
+    }
+
+    pub fn transform_direction(&self, direction: Vector3) -> Vector3 {
+        self.real * direction
+    }
+
+    pub fn dot(a: DualQuaternion, b: DualQuaternion) -> f32 {
+        Quaternion::dot(a.real, b.real)
+    }
+
+    pub fn conjugate(&self) -> DualQuaternion {
+        DualQuaternion {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate()
+        }
+    }
+
+    pub fn inverse(&self) -> DualQuaternion {
+        let real = self.real.inverse();
+
+        DualQuaternion {
+            real,
+            dual: real * self.dual * real * -1.0
+        }
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        self.real.magnitude()
+    }
+
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    // Scales to a unit real part and removes any dual component parallel to it, which keeps the result a
+    // valid rigid transform after blending or accumulated error
+    pub fn normalized(&self) -> DualQuaternion {
+        let mag = self.real.magnitude();
+        if mag <= EPSILON || mag.is_nan() {
+            return DualQuaternion::IDENTITY;
+        }
+
+        let real = self.real * (1.0 / mag);
+        let dual = self.dual * (1.0 / mag);
+
+        DualQuaternion {
+            real,
+            dual: dual - real * Quaternion::dot(real, dual)
+        }
+    }
+
+    pub fn sclerp(from: DualQuaternion, to: DualQuaternion, t: f32) -> DualQuaternion {
+        DualQuaternion::sclerp_unclamped(from, to, t.clamp01())
+    }
+
+    pub fn sclerp_unclamped(from: DualQuaternion, to: DualQuaternion, t: f32) -> DualQuaternion {
+        let to = if DualQuaternion::dot(from, to) < 0.0 { to * -1.0 } else { to };
+        let diff = from.conjugate() * to;
+
+        from * diff.pow(t)
+    }
+
+    // Dual quaternion linear blending, weights are expected to sum to one. Panics if there is not exactly one
+    // weight per dual quaternion
+    pub fn dlb(dual_quaternions: &[DualQuaternion], weights: &[f32]) -> DualQuaternion {
+        assert_eq!(dual_quaternions.len(), weights.len(), "dlb needs one weight per dual quaternion");
+
+        let pivot = match dual_quaternions.first() {
+            Some(dq) => *dq,
+            None => return DualQuaternion::IDENTITY
+        };
+
+        let mut blended = DualQuaternion::new(Quaternion::new(0.0, 0.0, 0.0, 0.0), Quaternion::new(0.0, 0.0, 0.0, 0.0));
+        for (dq, weight) in dual_quaternions.iter().zip(weights) {
+            let weight = if DualQuaternion::dot(pivot, *dq) < 0.0 { -weight } else { *weight };
+
+            blended += *dq * weight;
+        }
+
+        if blended.real.sqr_magnitude() < EPSILON {
+            return DualQuaternion::IDENTITY;
+        }
+
+        blended.normalized()
+    }
+
+    // Raises a unit dual quaternion to a power by scaling its screw parameters
+    fn pow(&self, t: f32) -> DualQuaternion {
+        let real_vec = Vector3::new(self.real.x, self.real.y, self.real.z);
+        let real_vec_mag = real_vec.magnitude();
+
+        if real_vec_mag < EPSILON {
+            return DualQuaternion::from_translation(self.translation() * t);
+        }
+
+        let dual_vec = Vector3::new(self.dual.x, self.dual.y, self.dual.z);
+
+        let angle = 2.0 * math::atan2(real_vec_mag, self.real.w);
+        let axis = real_vec / real_vec_mag;
+        let pitch = -2.0 * self.dual.w / real_vec_mag;
+        let moment = (dual_vec - axis * (pitch * self.real.w * 0.5)) / real_vec_mag;
+
+        let half_angle = angle * t * 0.5;
+        let half_pitch = pitch * t * 0.5;
+        let sin_half = math::sin(half_angle);
+        let cos_half = math::cos(half_angle);
+
+        let real = axis * sin_half;
+        let dual = moment * sin_half + axis * (half_pitch * cos_half);
+
+        DualQuaternion {
+            real: Quaternion::new(real.x, real.y, real.z, cos_half),
+            dual: Quaternion::new(dual.x, dual.y, dual.z, -half_pitch * sin_half)
+        }
+    }
+}
+
+impl fmt::Debug for DualQuaternion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({:?}, {:?})", self.real, self.dual)
+    }
+}
+
+impl fmt::Display for DualQuaternion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.real, self.dual)
+    }
+}
+
+impl PartialEq for DualQuaternion {
+    fn eq(&self, other: &DualQuaternion) -> bool {
+        self.approx_eq(*other)
+    }
+}
+
+impl Eq for DualQuaternion {}
+
+impl_op! { ApproxEq,
+    fn approx_eq(self: DualQuaternion, other: DualQuaternion) -> bool {
+        self.real.approx_eq(other.real) && self.translation().approx_eq(other.translation())
+    }
+}
+
+impl_op! { Add,
+    fn add(self: DualQuaternion, other: DualQuaternion) -> DualQuaternion {
+        DualQuaternion {
+            real: self.real + other.real,
+            dual: self.dual + other.dual
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: DualQuaternion, other: DualQuaternion) -> DualQuaternion {
+        DualQuaternion {
+            real: self.real * other.real,
+            dual: self.real * other.dual + self.dual * other.real
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: DualQuaternion, other: Vector3) -> Vector3 {
+        self.transform_point(other)
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: DualQuaternion, other: f32) -> DualQuaternion {
+        DualQuaternion {
+            real: self.real * other,
+            dual: self.dual * other
+        }
+    }
+}
+
+impl_op! { AddAssign,
+    fn add_assign(&mut self: DualQuaternion, other: DualQuaternion) {
+        self.real += other.real;
+        self.dual += other.dual;
+    }
+}
+
+impl_op! { MulAssign,
+    fn mul_assign(&mut self: DualQuaternion, other: f32) {
+        self.real *= other;
+        self.dual *= other;
+    }
+}
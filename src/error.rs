@@ -0,0 +1,33 @@
+use core::fmt;
+
+// Reasons the try_ functions refuse to produce a value
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MathError {
+    // Too short to normalize, the length is at most EPSILON
+    ZeroLength,
+    // No inverse exists or it does not fit in an f32
+    Singular,
+    // The directions are parallel so they do not define a basis
+    DegenerateBasis,
+    // The last row of the matrix is not (0, 0, 0, 1) so it has no affine equivalent
+    NotAffine,
+    // The input holds NaN or an infinity
+    NonFinite
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match *self {
+            MathError::ZeroLength => "length is too close to zero",
+            MathError::Singular => "value is not invertible",
+            MathError::DegenerateBasis => "directions are parallel and do not define a basis",
+            MathError::NotAffine => "matrix is projective and has no affine equivalent",
+            MathError::NonFinite => "value is not finite"
+        };
+
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for MathError {}
@@ -0,0 +1,567 @@
+use core::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
+use core::cmp::{ PartialEq, Eq, Ord, PartialOrd };
+use core::fmt;
+
+use { Clamp, Clamp01 };
+
+// atan(2^-i) for the CORDIC iterations in Q2.62
+const ATAN_TABLE: [i64; 62] = [
+    3622009729038561421, 2138197195906305897, 1129764675555192497, 573486189672913778,
+    287855953345232185, 144068303048368715, 72051730834756822, 36028064038054493,
+    18014306884351854, 9007187801521084, 4503598195715550, 2251799634728303,
+    1125899884473003, 562949950625109, 281474976361131, 140737488311637,
+    70368744172203, 35184372088149, 17592186044331, 8796093022197,
+    4398046511103, 2199023255552, 1099511627776, 549755813888,
+    274877906944, 137438953472, 68719476736, 34359738368,
+    17179869184, 8589934592, 4294967296, 2147483648,
+    1073741824, 536870912, 268435456, 134217728,
+    67108864, 33554432, 16777216, 8388608,
+    4194304, 2097152, 1048576, 524288,
+    262144, 131072, 65536, 32768,
+    16384, 8192, 4096, 2048,
+    1024, 512, 256, 128,
+    64, 32, 16, 8,
+    4, 2
+];
+
+// Reciprocal of the CORDIC gain in Q2.62
+const CORDIC_GAIN: i64 = 2800459870029452954;
+
+// Pi in Q3.61
+const PI_Q61: i64 = 7244019458077122842;
+
+// ln(2) in Q2.62
+const LN2_Q62: i64 = 3196577161300663915;
+
+// $raw holds the value with $frac fractional bits, $wide is used for intermediate products.
+// Trig runs in $wide with $work fractional bits so the CORDIC iterations keep guard bits below the result
+macro_rules! impl_fixed {
+    ($t:ident, $raw:ty, $wide:ty, $uwide:ty, $frac:expr, $work:expr) => {
+        #[derive(Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $t($raw);
+
+        #[allow(dead_code)]
+        impl $t {
+            pub const FRAC_BITS: u32 = $frac;
+
+            pub const ZERO: $t = $t(0);
+            pub const ONE: $t = $t(1 << $frac);
+            pub const HALF: $t = $t(1 << ($frac - 1));
+            pub const MIN: $t = $t(<$raw>::MIN);
+            pub const MAX: $t = $t(<$raw>::MAX);
+
+            // Smallest positive value
+            pub const EPSILON: $t = $t(1);
+
+            pub const PI: $t = $t(((PI_Q61 + (1 << (60 - $frac))) >> (61 - $frac)) as $raw);
+            pub const HALF_PI: $t = $t(((PI_Q61 + (1 << (61 - $frac))) >> (62 - $frac)) as $raw);
+            pub const TWO_PI: $t = $t(((PI_Q61 + (1 << (59 - $frac))) >> (60 - $frac)) as $raw);
+
+            pub const fn from_bits(bits: $raw) -> $t {
+                $t(bits)
+            }
+
+            pub fn to_bits(self) -> $raw {
+                self.0
+            }
+
+            // Saturates when the integer is out of range
+            pub fn from_int(value: $raw) -> $t {
+                $t::saturate((value as $wide) << $frac)
+            }
+
+            // Rounds towards negative infinity
+            pub fn to_int(self) -> $raw {
+                self.0 >> $frac
+            }
+
+            // Rounds to the nearest representable value, saturates when out of range and maps NaN to zero
+            pub fn from_f32(value: f32) -> $t {
+                $t::from_f64(value as f64)
+            }
+
+            pub fn from_f64(value: f64) -> $t {
+                let scaled = value * (1u64 << $frac) as f64;
+
+                $t(if scaled >= 0.0 { scaled + 0.5 } else { scaled - 0.5 } as $raw)
+            }
+
+            pub fn to_f32(self) -> f32 {
+                self.0 as f32 / (1u64 << $frac) as f32
+            }
+
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / (1u64 << $frac) as f64
+            }
+
+            pub fn wrapping_add(self, other: $t) -> $t {
+                $t(self.0.wrapping_add(other.0))
+            }
+
+            pub fn wrapping_sub(self, other: $t) -> $t {
+                $t(self.0.wrapping_sub(other.0))
+            }
+
+            pub fn wrapping_mul(self, other: $t) -> $t {
+                $t($t::mul_wide(self, other) as $raw)
+            }
+
+            // Panics if other is zero
+            pub fn wrapping_div(self, other: $t) -> $t {
+                $t($t::div_wide(self, other) as $raw)
+            }
+
+            pub fn wrapping_neg(self) -> $t {
+                $t(self.0.wrapping_neg())
+            }
+
+            pub fn saturating_add(self, other: $t) -> $t {
+                $t(self.0.saturating_add(other.0))
+            }
+
+            pub fn saturating_sub(self, other: $t) -> $t {
+                $t(self.0.saturating_sub(other.0))
+            }
+
+            pub fn saturating_mul(self, other: $t) -> $t {
+                $t::saturate($t::mul_wide(self, other))
+            }
+
+            // Panics if other is zero
+            pub fn saturating_div(self, other: $t) -> $t {
+                $t::saturate($t::div_wide(self, other))
+            }
+
+            pub fn saturating_neg(self) -> $t {
+                $t(self.0.saturating_neg())
+            }
+
+            pub fn saturating_abs(self) -> $t {
+                $t(self.0.saturating_abs())
+            }
+
+            pub fn checked_div(self, other: $t) -> Option<$t> {
+                if other.0 == 0 {
+                    return None;
+                }
+
+                let result = $t::div_wide(self, other);
+                if result > <$raw>::MAX as $wide || result < <$raw>::MIN as $wide {
+                    return None;
+                }
+
+                Some($t(result as $raw))
+            }
+
+            pub fn abs(self) -> $t {
+                $t(self.0.wrapping_abs())
+            }
+
+            pub fn signum(self) -> $t {
+                if self.0 > 0 {
+                    $t::ONE
+                }
+                else if self.0 < 0 {
+                    -$t::ONE
+                }
+                else {
+                    $t::ZERO
+                }
+            }
+
+            pub fn is_negative(self) -> bool {
+                self.0 < 0
+            }
+
+            pub fn floor(self) -> $t {
+                $t(self.0 & !((1 << $frac) - 1))
+            }
+
+            pub fn ceil(self) -> $t {
+                $t::floor($t(self.0.wrapping_add((1 << $frac) - 1)))
+            }
+
+            // Rounds half away from zero like f32::round
+            pub fn round(self) -> $t {
+                if self.0 < 0 {
+                    -$t::floor($t(self.0.wrapping_neg().wrapping_add(1 << ($frac - 1))))
+                }
+                else {
+                    $t::floor($t(self.0.wrapping_add(1 << ($frac - 1))))
+                }
+            }
+
+            // self - floor(self), never negative unlike f32::fract
+            pub fn fract(self) -> $t {
+                $t(self.0 & ((1 << $frac) - 1))
+            }
+
+            pub fn min(self, other: $t) -> $t {
+                if self < other { self } else { other }
+            }
+
+            pub fn max(self, other: $t) -> $t {
+                if self > other { self } else { other }
+            }
+
+            pub fn lerp(start: $t, end: $t, t: $t) -> $t {
+                start + (end - start) * t.clamp01()
+            }
+
+            pub fn lerp_unclamped(start: $t, end: $t, t: $t) -> $t {
+                start + (end - start) * t
+            }
+
+            // Negative values have no real root and return zero
+            pub fn sqrt(self) -> $t {
+                if self.0 <= 0 {
+                    return $t::ZERO;
+                }
+
+                let mut op = (self.0 as $uwide) << $frac;
+                let mut result: $uwide = 0;
+                let mut bit: $uwide = 1 << (<$uwide>::BITS - 2);
+                while bit > op {
+                    bit >>= 2;
+                }
+
+                while bit != 0 {
+                    if op >= result + bit {
+                        op -= result + bit;
+                        result = (result >> 1) + bit;
+                    }
+                    else {
+                        result >>= 1;
+                    }
+                    bit >>= 2;
+                }
+
+                $t(result as $raw)
+            }
+
+            pub fn sin(self) -> $t {
+                self.sin_cos().0
+            }
+
+            pub fn cos(self) -> $t {
+                self.sin_cos().1
+            }
+
+            // Saturates where the tangent is undefined
+            pub fn tan(self) -> $t {
+                let (sin, cos) = self.sin_cos();
+                if cos.0 == 0 {
+                    return if sin.0 < 0 { $t::MIN } else { $t::MAX };
+                }
+
+                sin.saturating_div(cos)
+            }
+
+            // CORDIC in rotation mode, the angle is first reduced to [-pi/2, pi/2] where it converges
+            pub fn sin_cos(self) -> ($t, $t) {
+                let pi = $t::work_pi();
+                let half_pi = pi >> 1;
+
+                let mut angle = ((self.0 as $wide) << ($work - $frac)) % (pi << 1);
+                if angle > pi {
+                    angle -= pi << 1;
+                }
+                else if angle < -pi {
+                    angle += pi << 1;
+                }
+
+                let mut negate = false;
+                if angle > half_pi {
+                    angle -= pi;
+                    negate = true;
+                }
+                else if angle < -half_pi {
+                    angle += pi;
+                    negate = true;
+                }
+
+                let mut x = $t::work_from_q62(CORDIC_GAIN);
+                let mut y: $wide = 0;
+                for i in 0..$work {
+                    let dx = y >> i;
+                    let dy = x >> i;
+                    let step = $t::work_from_q62(ATAN_TABLE[i]);
+                    if angle >= 0 {
+                        x -= dx;
+                        y += dy;
+                        angle -= step;
+                    }
+                    else {
+                        x += dx;
+                        y -= dy;
+                        angle += step;
+                    }
+                }
+
+                if negate {
+                    x = -x;
+                    y = -y;
+                }
+
+                ($t::from_work(y), $t::from_work(x))
+            }
+
+            // CORDIC in vectoring mode, the left half plane is rotated by pi first
+            pub fn atan2(y: $t, x: $t) -> $t {
+                if x.0 == 0 && y.0 == 0 {
+                    return $t::ZERO;
+                }
+
+                let mut vx = (x.0 as $wide) << ($work - $frac);
+                let mut vy = (y.0 as $wide) << ($work - $frac);
+                let mut angle: $wide = 0;
+                if vx < 0 {
+                    angle = if vy < 0 { -$t::work_pi() } else { $t::work_pi() };
+                    vx = -vx;
+                    vy = -vy;
+                }
+
+                for i in 0..$work {
+                    let dx = vy >> i;
+                    let dy = vx >> i;
+                    let step = $t::work_from_q62(ATAN_TABLE[i]);
+                    if vy > 0 {
+                        vx += dx;
+                        vy -= dy;
+                        angle += step;
+                    }
+                    else {
+                        vx -= dx;
+                        vy += dy;
+                        angle -= step;
+                    }
+                }
+
+                $t::from_work(angle)
+            }
+
+            pub fn atan(self) -> $t {
+                $t::atan2(self, $t::ONE)
+            }
+
+            // Inputs outside [-1, 1] are clamped
+            pub fn asin(self) -> $t {
+                let x = Clamp::clamp(self, -$t::ONE, $t::ONE);
+
+                $t::atan2(x, ($t::ONE - x * x).sqrt())
+            }
+
+            pub fn acos(self) -> $t {
+                let x = Clamp::clamp(self, -$t::ONE, $t::ONE);
+
+                $t::atan2(($t::ONE - x * x).sqrt(), x)
+            }
+
+            // e^r * 2^k with |r| <= ln(2) / 2, the series for e^r runs until its terms vanish. Saturates at MAX and
+            // rounds to zero once the result drops below EPSILON
+            pub fn exp(self) -> $t {
+                let ln2 = $t::work_from_q62(LN2_Q62);
+                let x = (self.0 as $wide) << ($work - $frac);
+                let k = (x + (ln2 >> 1)).div_euclid(ln2);
+                let r = x - k * ln2;
+
+                let bits = <$raw>::BITS as $wide;
+                if k >= bits - $frac {
+                    return $t::MAX;
+                }
+
+                if k < -bits {
+                    return $t::ZERO;
+                }
+
+                let mut sum: $wide = 1 << $work;
+                let mut term: $wide = 1 << $work;
+                let mut n: $wide = 1;
+                while term != 0 {
+                    term = ((term * r) >> $work) / n;
+                    sum += term;
+                    n += 1;
+                }
+
+                let shift = k - ($work - $frac);
+                if shift >= 0 {
+                    $t::saturate(sum << shift)
+                }
+                else {
+                    $t::saturate((sum + (1 << (-shift - 1))) >> -shift)
+                }
+            }
+
+            // m * 2^e with m in [1, 2), ln(m) = 2 * atanh((m - 1) / (m + 1)). Values at or below zero have no
+            // logarithm and saturate to MIN
+            pub fn ln(self) -> $t {
+                if self.0 <= 0 {
+                    return $t::MIN;
+                }
+
+                let top = (<$raw>::BITS - 1 - self.0.leading_zeros()) as $wide;
+                let m = if top > $work {
+                    (self.0 as $wide) >> (top - $work)
+                }
+                else {
+                    (self.0 as $wide) << ($work - top)
+                };
+
+                let one: $wide = 1 << $work;
+                let s = ((m - one) << $work) / (m + one);
+                let s_sqr = (s * s) >> $work;
+
+                let mut sum: $wide = 0;
+                let mut term = s;
+                let mut n: $wide = 1;
+                while term != 0 {
+                    sum += term / n;
+                    term = (term * s_sqr) >> $work;
+                    n += 2;
+                }
+
+                $t::from_work(sum * 2 + (top - $frac) * $t::work_from_q62(LN2_Q62))
+            }
+
+            fn mul_wide(a: $t, b: $t) -> $wide {
+                ((a.0 as $wide) * (b.0 as $wide) + (1 << ($frac - 1))) >> $frac
+            }
+
+            fn div_wide(a: $t, b: $t) -> $wide {
+                ((a.0 as $wide) << $frac) / (b.0 as $wide)
+            }
+
+            fn saturate(value: $wide) -> $t {
+                if value > <$raw>::MAX as $wide {
+                    $t::MAX
+                }
+                else if value < <$raw>::MIN as $wide {
+                    $t::MIN
+                }
+                else {
+                    $t(value as $raw)
+                }
+            }
+
+            fn work_pi() -> $wide {
+                ((PI_Q61 as $wide) + (1 << (60 - $work))) >> (61 - $work)
+            }
+
+            fn work_from_q62(value: i64) -> $wide {
+                ((value as $wide) + (1 << (61 - $work))) >> (62 - $work)
+            }
+
+            fn from_work(value: $wide) -> $t {
+                $t(((value + (1 << ($work - $frac - 1))) >> ($work - $frac)) as $raw)
+            }
+        }
+
+        impl Clamp for $t {
+            fn clamp(self, min: $t, max: $t) -> $t {
+                if self < min {
+                    return min;
+                }
+
+                if self > max {
+                    return max;
+                }
+
+                self
+            }
+        }
+
+        impl Clamp01 for $t {
+            fn clamp01(self) -> $t {
+                Clamp::clamp(self, $t::ZERO, $t::ONE)
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.to_f64())
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.to_f64())
+            }
+        }
+
+        // Operators wrap on overflow in every build profile so results never depend on debug assertions
+        impl_op! { Add,
+            fn add(self: $t, other: $t) -> $t {
+                self.wrapping_add(other)
+            }
+        }
+
+        impl_op! { Sub,
+            fn sub(self: $t, other: $t) -> $t {
+                self.wrapping_sub(other)
+            }
+        }
+
+        impl_op! { Mul,
+            fn mul(self: $t, other: $t) -> $t {
+                self.wrapping_mul(other)
+            }
+        }
+
+        impl_op! { Div,
+            fn div(self: $t, other: $t) -> $t {
+                self.wrapping_div(other)
+            }
+        }
+
+        impl_op! { Neg,
+            fn neg(self: $t) -> $t {
+                self.wrapping_neg()
+            }
+        }
+
+        impl_op! { AddAssign,
+            fn add_assign(&mut self: $t, other: $t) {
+                *self = self.wrapping_add(other);
+            }
+        }
+
+        impl_op! { SubAssign,
+            fn sub_assign(&mut self: $t, other: $t) {
+                *self = self.wrapping_sub(other);
+            }
+        }
+
+        impl_op! { MulAssign,
+            fn mul_assign(&mut self: $t, other: $t) {
+                *self = self.wrapping_mul(other);
+            }
+        }
+
+        impl_op! { DivAssign,
+            fn div_assign(&mut self: $t, other: $t) {
+                *self = self.wrapping_div(other);
+            }
+        }
+    };
+}
+
+impl_fixed!(Fixed32, i32, i64, u64, 16, 30);
+impl_fixed!(Fixed64, i64, i128, u128, 32, 60);
+
+impl From<Fixed32> for Fixed64 {
+    fn from(value: Fixed32) -> Fixed64 {
+        Fixed64::from_bits((value.to_bits() as i64) << 16)
+    }
+}
+
+impl From<i16> for Fixed32 {
+    fn from(value: i16) -> Fixed32 {
+        Fixed32::from_int(value as i32)
+    }
+}
+
+impl From<i32> for Fixed64 {
+    fn from(value: i32) -> Fixed64 {
+        Fixed64::from_int(value as i64)
+    }
+}
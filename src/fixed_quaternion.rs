@@ -0,0 +1,594 @@
+use core::ops::{ Add, Sub, Mul, Div, Neg };
+use core::fmt;
+
+use { Clamp01, Fixed64, FixedVector3, MathError, Quaternion };
+use fixed_vector3::PARALLEL_EPSILON;
+
+// 0.5 - 2^-20, the same pole margin as Quaternion::to_euler
+const POLE_TEST: Fixed64 = Fixed64::from_bits((1 << 31) - (1 << 12));
+
+// Quaternion counterpart on Q32.32 components
+#[derive(Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct FixedQuaternion {
+    pub x: Fixed64,
+    pub y: Fixed64,
+    pub z: Fixed64,
+    pub w: Fixed64
+}
+
+#[allow(dead_code)]
+impl FixedQuaternion {
+    pub const IDENTITY: FixedQuaternion = FixedQuaternion { x: Fixed64::ZERO, y: Fixed64::ZERO, z: Fixed64::ZERO, w: Fixed64::ONE };
+
+    pub fn new(x: Fixed64, y: Fixed64, z: Fixed64, w: Fixed64) -> FixedQuaternion {
+        FixedQuaternion {
+            x,
+            y,
+            z,
+            w
+        }
+    }
+
+    pub fn from_direction(forward: FixedVector3) -> FixedQuaternion {
+        FixedQuaternion::from_orientation(forward, FixedVector3::UP)
+    }
+
+    pub fn from_orientation(forward: FixedVector3, up: FixedVector3) -> FixedQuaternion {
+        FixedQuaternion::look_rotation(forward, up)
+    }
+
+    pub fn from_to_rotation(from: FixedVector3, to: FixedVector3) -> FixedQuaternion {
+        let from = from.normalized();
+        let to = to.normalized();
+        let dot = FixedVector3::dot(from, to);
+
+        if dot >= Fixed64::ONE - PARALLEL_EPSILON {
+            return FixedQuaternion::IDENTITY;
+        }
+
+        // Opposite vectors have no unique axis, rotate half a turn around any perpendicular
+        if dot <= PARALLEL_EPSILON - Fixed64::ONE {
+            let mut axis = FixedVector3::cross(FixedVector3::RIGHT, from);
+            if axis.sqr_magnitude() < PARALLEL_EPSILON {
+                axis = FixedVector3::cross(FixedVector3::UP, from);
+            }
+
+            return FixedQuaternion::from_angle_axis(Fixed64::PI, axis.normalized());
+        }
+
+        let axis = FixedVector3::cross(from, to);
+
+        FixedQuaternion {
+            x: axis.x,
+            y: axis.y,
+            z: axis.z,
+            w: Fixed64::ONE + dot
+        }.normalized()
+    }
+
+    pub fn look_rotation(forward: FixedVector3, up: FixedVector3) -> FixedQuaternion {
+        let forward = forward.normalized();
+        if forward == FixedVector3::ZERO {
+            return FixedQuaternion::IDENTITY;
+        }
+
+        // When forward is parallel to up the basis is undefined, fall back to the shortest arc
+        let right = FixedVector3::cross(up.normalized(), forward);
+        if right.sqr_magnitude() < PARALLEL_EPSILON {
+            return FixedQuaternion::from_to_rotation(FixedVector3::FORWARD, forward);
+        }
+
+        let right = right.normalized();
+        let up = FixedVector3::cross(forward, right);
+
+        let m00 = right.x;
+        let m10 = right.y;
+        let m20 = right.z;
+        let m01 = up.x;
+        let m11 = up.y;
+        let m21 = up.z;
+        let m02 = forward.x;
+        let m12 = forward.y;
+        let m22 = forward.z;
+
+        let mut q = FixedQuaternion {
+            x: (Fixed64::ONE + m00 - m11 - m22).sqrt() * Fixed64::HALF,
+            y: (Fixed64::ONE - m00 + m11 - m22).sqrt() * Fixed64::HALF,
+            z: (Fixed64::ONE - m00 - m11 + m22).sqrt() * Fixed64::HALF,
+            w: (Fixed64::ONE + m00 + m11 + m22).sqrt() * Fixed64::HALF
+        };
+
+        // Same signs as Quaternion::look_rotation, where a zero difference keeps the component positive
+        if (m21 - m12).is_negative() {
+            q.x = -q.x;
+        }
+        if (m02 - m20).is_negative() {
+            q.y = -q.y;
+        }
+        if (m10 - m01).is_negative() {
+            q.z = -q.z;
+        }
+
+        q
+    }
+
+    pub fn from_angle_axis(angle: Fixed64, axis: FixedVector3) -> FixedQuaternion {
+        let (sin, cos) = (angle * Fixed64::HALF).sin_cos();
+
+        FixedQuaternion {
+            x: axis.x * sin,
+            y: axis.y * sin,
+            z: axis.z * sin,
+            w: cos
+        }
+    }
+
+    pub fn from_euler(euler: FixedVector3) -> FixedQuaternion {
+        FixedQuaternion::from_euler_components(euler.x, euler.y, euler.z)
+    }
+
+    // Same component order as Quaternion::from_euler_components
+    pub fn from_euler_components(x: Fixed64, y: Fixed64, z: Fixed64) -> FixedQuaternion {
+        let (sin_x, cos_x) = (x * Fixed64::HALF).sin_cos();
+        let (sin_y, cos_y) = (y * Fixed64::HALF).sin_cos();
+        let (sin_z, cos_z) = (z * Fixed64::HALF).sin_cos();
+
+        FixedQuaternion {
+            x: sin_y * sin_x * cos_z + cos_y * cos_x * sin_z,
+            y: sin_y * cos_x * cos_z + cos_y * sin_x * sin_z,
+            z: cos_y * sin_x * cos_z - sin_y * cos_x * sin_z,
+            w: cos_y * cos_x * cos_z - sin_y * sin_x * sin_z
+        }
+    }
+
+    pub fn forward(&self) -> FixedVector3 {
+        self * FixedVector3::FORWARD
+    }
+
+    pub fn right(&self) -> FixedVector3 {
+        self * FixedVector3::RIGHT
+    }
+
+    pub fn up(&self) -> FixedVector3 {
+        self * FixedVector3::UP
+    }
+
+    pub fn to_euler(&self) -> FixedVector3 {
+        let two = Fixed64::from_int(2);
+        let x_sqr = self.x * self.x;
+        let y_sqr = self.y * self.y;
+        let z_sqr = self.z * self.z;
+        let w_sqr = self.w * self.w;
+
+        // A zero quaternion has no orientation and comes out as zero angles
+        let unit = x_sqr + y_sqr + z_sqr + w_sqr;
+        if unit == Fixed64::ZERO {
+            return FixedVector3::ZERO;
+        }
+
+        // Yaw and roll combine at the poles, see Quaternion::to_euler
+        let test = self.x * self.y + self.z * self.w;
+        if test > unit * POLE_TEST {
+            return FixedVector3 {
+                x: Fixed64::HALF_PI,
+                y: two * Fixed64::atan2(self.y, self.w),
+                z: Fixed64::ZERO
+            };
+        }
+
+        if test < -unit * POLE_TEST {
+            return FixedVector3 {
+                x: -Fixed64::HALF_PI,
+                y: two * Fixed64::atan2(self.y, self.w),
+                z: Fixed64::ZERO
+            };
+        }
+
+        FixedVector3 {
+            x: (two * test).saturating_div(unit).asin(),
+            y: Fixed64::atan2(two * self.y * self.w - two * self.x * self.z, x_sqr - y_sqr - z_sqr + w_sqr),
+            z: Fixed64::atan2(two * self.x * self.w - two * self.y * self.z, -x_sqr + y_sqr - z_sqr + w_sqr)
+        }
+    }
+
+    pub fn to_angle_axis(&self, out_angle: &mut Fixed64, out_axis: &mut FixedVector3) {
+        let q = self.normalized();
+
+        *out_angle = q.w.acos() * Fixed64::from_int(2);
+        let s = (Fixed64::ONE - q.w * q.w).sqrt();
+        if s == Fixed64::ZERO {
+            *out_axis = FixedVector3::new(q.x, q.y, q.z);
+        }
+        else {
+            *out_axis = FixedVector3::new(q.x / s, q.y / s, q.z / s);
+        }
+    }
+
+    pub fn dot(a: FixedQuaternion, b: FixedQuaternion) -> Fixed64 {
+        a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+    }
+
+    pub fn lerp(from: FixedQuaternion, to: FixedQuaternion, t: Fixed64) -> FixedQuaternion {
+        FixedQuaternion::lerp_unclamped(from, to, t.clamp01())
+    }
+
+    pub fn lerp_unclamped(from: FixedQuaternion, to: FixedQuaternion, t: Fixed64) -> FixedQuaternion {
+        from * (Fixed64::ONE - t) + to * t
+    }
+
+    pub fn slerp(from: FixedQuaternion, to: FixedQuaternion, t: Fixed64) -> FixedQuaternion {
+        FixedQuaternion::slerp_unclamped(from, to, t.clamp01())
+    }
+
+    // Takes the shorter arc by negating `to` when the quaternions point into opposite hemispheres
+    pub fn slerp_unclamped(from: FixedQuaternion, to: FixedQuaternion, t: Fixed64) -> FixedQuaternion {
+        let mut cos_theta = FixedQuaternion::dot(from, to);
+        let to = if cos_theta.is_negative() {
+            cos_theta = -cos_theta;
+            -to
+        }
+        else {
+            to
+        };
+
+        if cos_theta >= Fixed64::ONE {
+            return from;
+        }
+
+        let sin_theta = (Fixed64::ONE - cos_theta * cos_theta).sqrt();
+        if sin_theta == Fixed64::ZERO {
+            return FixedQuaternion::lerp_unclamped(from, to, t).normalized();
+        }
+
+        let theta = cos_theta.acos();
+        let ratio_a = ((Fixed64::ONE - t) * theta).sin() / sin_theta;
+        let ratio_b = (t * theta).sin() / sin_theta;
+
+        from * ratio_a + to * ratio_b
+    }
+
+    pub fn rotate_towards(from: FixedQuaternion, to: FixedQuaternion, max_radians_delta: Fixed64) -> FixedQuaternion {
+        let angle = FixedQuaternion::angle_between(from, to);
+        if angle == Fixed64::ZERO {
+            return to;
+        }
+
+        let to = if FixedQuaternion::dot(from, to).is_negative() { -to } else { to };
+        let t = max_radians_delta.saturating_div(angle).min(Fixed64::ONE);
+
+        FixedQuaternion::slerp_direct(from, to, t)
+    }
+
+    pub fn angle_between(a: FixedQuaternion, b: FixedQuaternion) -> Fixed64 {
+        let dot = FixedQuaternion::dot(a.normalized(), b.normalized()).abs();
+
+        dot.acos() * Fixed64::from_int(2)
+    }
+
+    // A zero vector part gives (0, 0, 0, e^w), e^w saturates like Fixed64::exp
+    pub fn exp(&self) -> FixedQuaternion {
+        let v = FixedVector3::new(self.x, self.y, self.z);
+        let v_mag = v.magnitude();
+        let exp_w = self.w.exp();
+
+        if v_mag == Fixed64::ZERO {
+            return FixedQuaternion {
+                x: Fixed64::ZERO,
+                y: Fixed64::ZERO,
+                z: Fixed64::ZERO,
+                w: exp_w
+            };
+        }
+
+        let (sin, cos) = v_mag.sin_cos();
+        let v = v.normalized() * (sin * exp_w);
+
+        FixedQuaternion {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: cos * exp_w
+        }
+    }
+
+    // The zero quaternion has no logarithm, its real part saturates to Fixed64::MIN like Fixed64::ln
+    pub fn ln(&self) -> FixedQuaternion {
+        let v = FixedVector3::new(self.x, self.y, self.z);
+        let v_mag = v.magnitude();
+        let ln_mag = self.magnitude().ln();
+
+        if v_mag == Fixed64::ZERO {
+            return FixedQuaternion {
+                x: Fixed64::ZERO,
+                y: Fixed64::ZERO,
+                z: Fixed64::ZERO,
+                w: ln_mag
+            };
+        }
+
+        let v = v.normalized() * Fixed64::atan2(v_mag, self.w);
+
+        FixedQuaternion {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: ln_mag
+        }
+    }
+
+    pub fn pow(&self, t: Fixed64) -> FixedQuaternion {
+        (self.ln() * t).exp()
+    }
+
+    // Same control points and interpolation as Quaternion::squad
+    pub fn squad(from: FixedQuaternion, to: FixedQuaternion, from_tangent: FixedQuaternion, to_tangent: FixedQuaternion, t: Fixed64) -> FixedQuaternion {
+        let t = t.clamp01();
+        let (to, to_tangent) = if FixedQuaternion::dot(from, to).is_negative() {
+            (-to, -to_tangent)
+        }
+        else {
+            (to, to_tangent)
+        };
+
+        let a = FixedQuaternion::slerp_direct(from, to, t);
+        let b = FixedQuaternion::slerp_direct(from_tangent, to_tangent, t);
+
+        FixedQuaternion::slerp_direct(a, b, Fixed64::from_int(2) * t * (Fixed64::ONE - t))
+    }
+
+    pub fn squad_tangent(prev: FixedQuaternion, current: FixedQuaternion, next: FixedQuaternion) -> FixedQuaternion {
+        let prev = if FixedQuaternion::dot(prev, current).is_negative() { -prev } else { prev };
+        let next = if FixedQuaternion::dot(next, current).is_negative() { -next } else { next };
+
+        let inv = current.inverse();
+        let sum = (inv * next).ln() + (inv * prev).ln();
+
+        current * (sum * Fixed64::from_f32(-0.25)).exp()
+    }
+
+    // Slerp without shortest path correction, squad relies on the control points keeping their hemisphere
+    fn slerp_direct(from: FixedQuaternion, to: FixedQuaternion, t: Fixed64) -> FixedQuaternion {
+        let cos_theta = FixedQuaternion::dot(from, to).clamp(-Fixed64::ONE, Fixed64::ONE);
+        let sin_theta = (Fixed64::ONE - cos_theta * cos_theta).sqrt();
+        if sin_theta == Fixed64::ZERO {
+            return FixedQuaternion::lerp_unclamped(from, to, t).normalized();
+        }
+
+        let theta = cos_theta.acos();
+        let ratio_a = ((Fixed64::ONE - t) * theta).sin() / sin_theta;
+        let ratio_b = (t * theta).sin() / sin_theta;
+
+        from * ratio_a + to * ratio_b
+    }
+
+    pub fn inverse(&self) -> FixedQuaternion {
+        self.checked_inverse().unwrap_or(FixedQuaternion::IDENTITY)
+    }
+
+    // Scales by the largest component like magnitude, None when the quaternion is zero or the inverse overflows
+    pub fn checked_inverse(&self) -> Option<FixedQuaternion> {
+        let largest = self.largest_component();
+        if largest == Fixed64::ZERO {
+            return None;
+        }
+
+        let scaled = FixedQuaternion {
+            x: self.x.checked_div(largest)?,
+            y: self.y.checked_div(largest)?,
+            z: self.z.checked_div(largest)?,
+            w: self.w.checked_div(largest)?
+        };
+        let sqr_norm = scaled.sqr_magnitude();
+
+        Some(FixedQuaternion {
+            x: (-scaled.x).checked_div(sqr_norm)?.checked_div(largest)?,
+            y: (-scaled.y).checked_div(sqr_norm)?.checked_div(largest)?,
+            z: (-scaled.z).checked_div(sqr_norm)?.checked_div(largest)?,
+            w: scaled.w.checked_div(sqr_norm)?.checked_div(largest)?
+        })
+    }
+
+    pub fn try_inverse(&self) -> Result<FixedQuaternion, MathError> {
+        self.checked_inverse().ok_or(MathError::Singular)
+    }
+
+    pub fn conjugate(&self) -> FixedQuaternion {
+        FixedQuaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w
+        }
+    }
+
+    // Scales by the largest component first so the squares cannot overflow, lengths past Fixed64::MAX saturate
+    pub fn magnitude(&self) -> Fixed64 {
+        let largest = self.largest_component();
+        if largest == Fixed64::ZERO {
+            return Fixed64::ZERO;
+        }
+
+        (*self / largest).sqr_magnitude().sqrt().saturating_mul(largest)
+    }
+
+    // Wraps once a component exceeds roughly 46341, magnitude and normalized scale first and do not
+    pub fn sqr_magnitude(&self) -> Fixed64 {
+        FixedQuaternion::dot(*self, *self)
+    }
+
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    // A zero quaternion has no orientation and normalizes to the identity
+    pub fn normalized(&self) -> FixedQuaternion {
+        let largest = self.largest_component();
+        if largest == Fixed64::ZERO {
+            return FixedQuaternion::IDENTITY;
+        }
+
+        let scaled = *self / largest;
+        scaled / scaled.sqr_magnitude().sqrt()
+    }
+
+    // Angular velocities are world space vectors in radians per second
+    pub fn derivative(&self, angular_velocity: FixedVector3) -> FixedQuaternion {
+        let omega = FixedQuaternion {
+            x: angular_velocity.x,
+            y: angular_velocity.y,
+            z: angular_velocity.z,
+            w: Fixed64::ZERO
+        };
+
+        omega * *self * Fixed64::HALF
+    }
+
+    pub fn integrate(&self, angular_velocity: FixedVector3, dt: Fixed64) -> FixedQuaternion {
+        (*self + self.derivative(angular_velocity) * dt).normalized()
+    }
+
+    pub fn integrate_exact(&self, angular_velocity: FixedVector3, dt: Fixed64) -> FixedQuaternion {
+        let speed = angular_velocity.magnitude();
+        if speed == Fixed64::ZERO {
+            return self.normalized();
+        }
+
+        let delta = FixedQuaternion::from_angle_axis(speed * dt, angular_velocity.normalized());
+
+        (delta * *self).normalized()
+    }
+
+    // A zero dt gives FixedVector3::ZERO, velocities too large for Q32.32 saturate
+    pub fn angular_velocity(from: FixedQuaternion, to: FixedQuaternion, dt: Fixed64) -> FixedVector3 {
+        if dt == Fixed64::ZERO {
+            return FixedVector3::ZERO;
+        }
+
+        let mut delta = to * from.conjugate();
+        if delta.w.is_negative() {
+            delta = -delta;
+        }
+
+        let log = delta.normalized().ln();
+        let two = Fixed64::from_int(2);
+
+        FixedVector3 {
+            x: (log.x * two).saturating_div(dt),
+            y: (log.y * two).saturating_div(dt),
+            z: (log.z * two).saturating_div(dt)
+        }
+    }
+
+    // Saturates so Fixed64::MIN, which has no absolute value, still scales down to unit range
+    fn largest_component(&self) -> Fixed64 {
+        self.x.saturating_abs().max(self.y.saturating_abs()).max(self.z.saturating_abs()).max(self.w.saturating_abs())
+    }
+}
+
+// Conversions
+impl From<Quaternion> for FixedQuaternion {
+    fn from(q: Quaternion) -> FixedQuaternion {
+        FixedQuaternion {
+            x: Fixed64::from_f32(q.x),
+            y: Fixed64::from_f32(q.y),
+            z: Fixed64::from_f32(q.z),
+            w: Fixed64::from_f32(q.w)
+        }
+    }
+}
+
+impl From<FixedQuaternion> for Quaternion {
+    fn from(q: FixedQuaternion) -> Quaternion {
+        Quaternion::new(q.x.to_f32(), q.y.to_f32(), q.z.to_f32(), q.w.to_f32())
+    }
+}
+
+// Formatting
+impl fmt::Debug for FixedQuaternion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl fmt::Display for FixedQuaternion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+// Ops
+impl_op! { Add,
+    fn add(self: FixedQuaternion, other: FixedQuaternion) -> FixedQuaternion {
+        FixedQuaternion {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w
+        }
+    }
+}
+
+impl_op! { Sub,
+    fn sub(self: FixedQuaternion, other: FixedQuaternion) -> FixedQuaternion {
+        FixedQuaternion {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w
+        }
+    }
+}
+
+impl_op! { Neg,
+    fn neg(self: FixedQuaternion) -> FixedQuaternion {
+        FixedQuaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: FixedQuaternion, other: FixedQuaternion) -> FixedQuaternion {
+        FixedQuaternion {
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z
+        }
+    }
+}
+
+// v + w * t + u x t with t = 2 * (u x v), u being the vector part
+impl_op! { Mul,
+    fn mul(self: FixedQuaternion, other: FixedVector3) -> FixedVector3 {
+        let u = FixedVector3::new(self.x, self.y, self.z);
+        let t = FixedVector3::cross(u, other) * Fixed64::from_int(2);
+
+        other + t * self.w + FixedVector3::cross(u, t)
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: FixedQuaternion, other: Fixed64) -> FixedQuaternion {
+        FixedQuaternion {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+            w: self.w * other
+        }
+    }
+}
+
+impl_op! { Div,
+    fn div(self: FixedQuaternion, other: Fixed64) -> FixedQuaternion {
+        FixedQuaternion {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+            w: self.w / other
+        }
+    }
+}
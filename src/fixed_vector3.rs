@@ -0,0 +1,401 @@
+use core::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
+use core::fmt;
+
+use { Clamp, Clamp01, Fixed64, FixedQuaternion, Vector3 };
+
+// Squared length below which the cross product of two unit vectors counts as parallel, f32::EPSILON in Q32.32
+pub(crate) const PARALLEL_EPSILON: Fixed64 = Fixed64::from_bits(1 << 9);
+
+// Vector3 counterpart on Q32.32 components, Q16.16 runs out of range as soon as lengths are squared
+#[derive(Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct FixedVector3 {
+    pub x: Fixed64,
+    pub y: Fixed64,
+    pub z: Fixed64
+}
+
+#[allow(dead_code)]
+impl FixedVector3 {
+    pub const ZERO: FixedVector3 = FixedVector3 { x: Fixed64::ZERO, y: Fixed64::ZERO, z: Fixed64::ZERO };
+    pub const ONE: FixedVector3 = FixedVector3 { x: Fixed64::ONE, y: Fixed64::ONE, z: Fixed64::ONE };
+    pub const FORWARD: FixedVector3 = FixedVector3 { x: Fixed64::ZERO, y: Fixed64::ZERO, z: Fixed64::ONE };
+    pub const RIGHT: FixedVector3 = FixedVector3 { x: Fixed64::ONE, y: Fixed64::ZERO, z: Fixed64::ZERO };
+    pub const UP: FixedVector3 = FixedVector3 { x: Fixed64::ZERO, y: Fixed64::ONE, z: Fixed64::ZERO };
+
+    pub fn new(x: Fixed64, y: Fixed64, z: Fixed64) -> FixedVector3 {
+        FixedVector3 {
+            x,
+            y,
+            z
+        }
+    }
+
+    pub fn clamp_magnitude(&self, max_length: Fixed64) -> FixedVector3 {
+        if self.magnitude() > max_length {
+            return self.normalized() * max_length
+        }
+
+        *self
+    }
+
+    pub fn sqr_magnitude(&self) -> Fixed64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    // Scales by the largest component first so the squares cannot overflow, lengths past Fixed64::MAX saturate
+    pub fn magnitude(&self) -> Fixed64 {
+        let largest = self.largest_component();
+        if largest == Fixed64::ZERO {
+            return Fixed64::ZERO;
+        }
+
+        (*self / largest).sqr_magnitude().sqrt().saturating_mul(largest)
+    }
+
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    pub fn normalized(&self) -> FixedVector3 {
+        let largest = self.largest_component();
+        if largest == Fixed64::ZERO {
+            return FixedVector3::ZERO;
+        }
+
+        let scaled = *self / largest;
+        scaled / scaled.sqr_magnitude().sqrt()
+    }
+
+    pub fn dot(a: FixedVector3, b: FixedVector3) -> Fixed64 {
+        a.x * b.x + a.y * b.y + a.z * b.z
+    }
+
+    pub fn cross(a: FixedVector3, b: FixedVector3) -> FixedVector3 {
+        FixedVector3 {
+            x: a.y * b.z - a.z * b.y,
+            y: a.z * b.x - a.x * b.z,
+            z: a.x * b.y - a.y * b.x
+        }
+    }
+
+    pub fn distance(a: FixedVector3, b: FixedVector3) -> Fixed64 {
+        (a - b).magnitude()
+    }
+
+    pub fn angle(a: FixedVector3, b: FixedVector3) -> Fixed64 {
+        FixedVector3::dot(a.normalized(), b.normalized()).acos()
+    }
+
+    pub fn scale(v: FixedVector3, other: FixedVector3) -> FixedVector3 {
+        FixedVector3 {
+            x: v.x * other.x,
+            y: v.y * other.y,
+            z: v.z * other.z
+        }
+    }
+
+    pub fn min(a: FixedVector3, b: FixedVector3) -> FixedVector3 {
+        FixedVector3 {
+            x: a.x.min(b.x),
+            y: a.y.min(b.y),
+            z: a.z.min(b.z)
+        }
+    }
+
+    pub fn max(a: FixedVector3, b: FixedVector3) -> FixedVector3 {
+        FixedVector3 {
+            x: a.x.max(b.x),
+            y: a.y.max(b.y),
+            z: a.z.max(b.z)
+        }
+    }
+
+    pub fn abs(&self) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs()
+        }
+    }
+
+    pub fn floor(&self) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x.floor(),
+            y: self.y.floor(),
+            z: self.z.floor()
+        }
+    }
+
+    pub fn ceil(&self) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x.ceil(),
+            y: self.y.ceil(),
+            z: self.z.ceil()
+        }
+    }
+
+    pub fn round(&self) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x.round(),
+            y: self.y.round(),
+            z: self.z.round()
+        }
+    }
+
+    pub fn signum(&self) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum()
+        }
+    }
+
+    pub fn min_element(&self) -> Fixed64 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    pub fn max_element(&self) -> Fixed64 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    pub fn lerp(start: FixedVector3, end: FixedVector3, t: Fixed64) -> FixedVector3 {
+        FixedVector3::lerp_unclamped(start, end, t.clamp01())
+    }
+
+    pub fn lerp_unclamped(start: FixedVector3, end: FixedVector3, t: Fixed64) -> FixedVector3 {
+        FixedVector3 {
+            x: start.x + (end.x - start.x) * t,
+            y: start.y + (end.y - start.y) * t,
+            z: start.z + (end.z - start.z) * t
+        }
+    }
+
+    pub fn move_towards(current: FixedVector3, target: FixedVector3, max_distance_delta: Fixed64) -> FixedVector3 {
+        let delta = target - current;
+        let distance = delta.magnitude();
+        if distance <= max_distance_delta || distance == Fixed64::ZERO {
+            return target;
+        }
+
+        current + delta / distance * max_distance_delta
+    }
+
+    pub fn rotate_towards(current: FixedVector3, target: FixedVector3, max_radians_delta: Fixed64, max_magnitude_delta: Fixed64) -> FixedVector3 {
+        let current_mag = current.magnitude();
+        let target_mag = target.magnitude();
+        if current_mag == Fixed64::ZERO || target_mag == Fixed64::ZERO {
+            return FixedVector3::move_towards(current, target, max_magnitude_delta);
+        }
+
+        let current_dir = current.normalized();
+        let target_dir = target.normalized();
+        let angle = FixedVector3::angle(current_dir, target_dir);
+
+        let direction = if angle <= max_radians_delta {
+            target_dir
+        }
+        else {
+            let mut axis = FixedVector3::cross(current_dir, target_dir);
+            if axis.sqr_magnitude() < PARALLEL_EPSILON {
+                axis = FixedVector3::cross(current_dir, FixedVector3::UP);
+                if axis.sqr_magnitude() < PARALLEL_EPSILON {
+                    axis = FixedVector3::cross(current_dir, FixedVector3::RIGHT);
+                }
+            }
+
+            FixedQuaternion::from_angle_axis(max_radians_delta, axis.normalized()) * current_dir
+        };
+
+        let delta_mag = target_mag - current_mag;
+        let magnitude = if delta_mag.abs() <= max_magnitude_delta {
+            target_mag
+        }
+        else {
+            current_mag + max_magnitude_delta * delta_mag.signum()
+        };
+
+        direction * magnitude
+    }
+
+    pub fn project(v: FixedVector3, normal: FixedVector3) -> FixedVector3 {
+        let dot = FixedVector3::dot(normal, normal);
+        if dot == Fixed64::ZERO {
+            FixedVector3::ZERO
+        }
+        else {
+            normal * FixedVector3::dot(v, normal) / dot
+        }
+    }
+
+    pub fn project_on_plane(v: FixedVector3, normal: FixedVector3) -> FixedVector3 {
+        v - FixedVector3::project(v, normal)
+    }
+
+    pub fn reflect(v: FixedVector3, normal: FixedVector3) -> FixedVector3 {
+        normal * (FixedVector3::dot(normal, v) * -Fixed64::from_int(2)) + v
+    }
+
+    // Saturates so Fixed64::MIN, which has no absolute value, still scales down to unit range
+    fn largest_component(&self) -> Fixed64 {
+        self.x.saturating_abs().max(self.y.saturating_abs()).max(self.z.saturating_abs())
+    }
+}
+
+impl Clamp for FixedVector3 {
+    fn clamp(self, min: FixedVector3, max: FixedVector3) -> FixedVector3 {
+        FixedVector3 {
+            x: Clamp::clamp(self.x, min.x, max.x),
+            y: Clamp::clamp(self.y, min.y, max.y),
+            z: Clamp::clamp(self.z, min.z, max.z)
+        }
+    }
+}
+
+// Conversions
+impl_element_conversions!(FixedVector3, Fixed64, 3, (Fixed64, Fixed64, Fixed64), x: 0, y: 1, z: 2);
+
+impl From<Vector3> for FixedVector3 {
+    fn from(v: Vector3) -> FixedVector3 {
+        FixedVector3 {
+            x: Fixed64::from_f32(v.x),
+            y: Fixed64::from_f32(v.y),
+            z: Fixed64::from_f32(v.z)
+        }
+    }
+}
+
+impl From<FixedVector3> for Vector3 {
+    fn from(v: FixedVector3) -> Vector3 {
+        Vector3::new(v.x.to_f32(), v.y.to_f32(), v.z.to_f32())
+    }
+}
+
+// Formatting
+impl fmt::Debug for FixedVector3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl fmt::Display for FixedVector3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+// Ops
+impl_op! { Add,
+    fn add(self: FixedVector3, other: FixedVector3) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z
+        }
+    }
+}
+
+impl_op! { Add,
+    fn add(self: FixedVector3, other: Fixed64) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x + other,
+            y: self.y + other,
+            z: self.z + other
+        }
+    }
+}
+
+impl_op! { Sub,
+    fn sub(self: FixedVector3, other: FixedVector3) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z
+        }
+    }
+}
+
+impl_op! { Sub,
+    fn sub(self: FixedVector3, other: Fixed64) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x - other,
+            y: self.y - other,
+            z: self.z - other
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: FixedVector3, other: FixedVector3) -> FixedVector3 {
+        FixedVector3::scale(self, other)
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: FixedVector3, other: Fixed64) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Fixed64, other: FixedVector3) -> FixedVector3 {
+        other * self
+    }
+}
+
+impl_op! { Div,
+    fn div(self: FixedVector3, other: Fixed64) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other
+        }
+    }
+}
+
+impl_op! { Div,
+    fn div(self: FixedVector3, other: FixedVector3) -> FixedVector3 {
+        FixedVector3 {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z
+        }
+    }
+}
+
+impl_op! { Neg,
+    fn neg(self: FixedVector3) -> FixedVector3 {
+        FixedVector3 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z
+        }
+    }
+}
+
+impl_op! { AddAssign,
+    fn add_assign(&mut self: FixedVector3, other: FixedVector3) {
+        *self = *self + other;
+    }
+}
+
+impl_op! { SubAssign,
+    fn sub_assign(&mut self: FixedVector3, other: FixedVector3) {
+        *self = *self - other;
+    }
+}
+
+impl_op! { MulAssign,
+    fn mul_assign(&mut self: FixedVector3, other: Fixed64) {
+        *self = *self * other;
+    }
+}
+
+impl_op! { DivAssign,
+    fn div_assign(&mut self: FixedVector3, other: Fixed64) {
+        *self = *self / other;
+    }
+}
@@ -0,0 +1,77 @@
+use cgmath;
+
+use { Vector2, Vector3, Vector4, Quaternion, Matrix3x3, Matrix4x4 };
+
+impl From<cgmath::Vector2<f32>> for Vector2 {
+    fn from(v: cgmath::Vector2<f32>) -> Vector2 {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2> for cgmath::Vector2<f32> {
+    fn from(v: Vector2) -> cgmath::Vector2<f32> {
+        cgmath::Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<cgmath::Vector3<f32>> for Vector3 {
+    fn from(v: cgmath::Vector3<f32>) -> Vector3 {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for cgmath::Vector3<f32> {
+    fn from(v: Vector3) -> cgmath::Vector3<f32> {
+        cgmath::Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<cgmath::Vector4<f32>> for Vector4 {
+    fn from(v: cgmath::Vector4<f32>) -> Vector4 {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4> for cgmath::Vector4<f32> {
+    fn from(v: Vector4) -> cgmath::Vector4<f32> {
+        cgmath::Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+// cgmath's constructor takes the scalar part first
+impl From<cgmath::Quaternion<f32>> for Quaternion {
+    fn from(q: cgmath::Quaternion<f32>) -> Quaternion {
+        Quaternion::new(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+impl From<Quaternion> for cgmath::Quaternion<f32> {
+    fn from(q: Quaternion) -> cgmath::Quaternion<f32> {
+        cgmath::Quaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+// cgmath matrices are stored as columns named x, y, z and w
+impl From<cgmath::Matrix3<f32>> for Matrix3x3 {
+    fn from(m: cgmath::Matrix3<f32>) -> Matrix3x3 {
+        Matrix3x3::new(m.x.into(), m.y.into(), m.z.into())
+    }
+}
+
+impl From<Matrix3x3> for cgmath::Matrix3<f32> {
+    fn from(m: Matrix3x3) -> cgmath::Matrix3<f32> {
+        cgmath::Matrix3::from_cols(m.column(0).into(), m.column(1).into(), m.column(2).into())
+    }
+}
+
+impl From<cgmath::Matrix4<f32>> for Matrix4x4 {
+    fn from(m: cgmath::Matrix4<f32>) -> Matrix4x4 {
+        Matrix4x4::new(m.x.into(), m.y.into(), m.z.into(), m.w.into())
+    }
+}
+
+impl From<Matrix4x4> for cgmath::Matrix4<f32> {
+    fn from(m: Matrix4x4) -> cgmath::Matrix4<f32> {
+        cgmath::Matrix4::from_cols(m.column(0).into(), m.column(1).into(), m.column(2).into(), m.column(3).into())
+    }
+}
@@ -0,0 +1,76 @@
+use glam;
+
+use { Vector2, Vector3, Vector4, Quaternion, Matrix3x3, Matrix4x4 };
+
+impl From<glam::Vec2> for Vector2 {
+    fn from(v: glam::Vec2) -> Vector2 {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2> for glam::Vec2 {
+    fn from(v: Vector2) -> glam::Vec2 {
+        glam::Vec2::new(v.x, v.y)
+    }
+}
+
+impl From<glam::Vec3> for Vector3 {
+    fn from(v: glam::Vec3) -> Vector3 {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for glam::Vec3 {
+    fn from(v: Vector3) -> glam::Vec3 {
+        glam::Vec3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<glam::Vec4> for Vector4 {
+    fn from(v: glam::Vec4) -> Vector4 {
+        Vector4::from(v.to_array())
+    }
+}
+
+impl From<Vector4> for glam::Vec4 {
+    fn from(v: Vector4) -> glam::Vec4 {
+        glam::Vec4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<glam::Quat> for Quaternion {
+    fn from(q: glam::Quat) -> Quaternion {
+        Quaternion::from(q.to_array())
+    }
+}
+
+impl From<Quaternion> for glam::Quat {
+    fn from(q: Quaternion) -> glam::Quat {
+        glam::Quat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+// glam stores matrices by column, conversions go through columns to keep each element at the same row and column
+impl From<glam::Mat3> for Matrix3x3 {
+    fn from(m: glam::Mat3) -> Matrix3x3 {
+        Matrix3x3::new(m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
+    }
+}
+
+impl From<Matrix3x3> for glam::Mat3 {
+    fn from(m: Matrix3x3) -> glam::Mat3 {
+        glam::Mat3::from_cols(m.column(0).into(), m.column(1).into(), m.column(2).into())
+    }
+}
+
+impl From<glam::Mat4> for Matrix4x4 {
+    fn from(m: glam::Mat4) -> Matrix4x4 {
+        Matrix4x4::new(m.x_axis.into(), m.y_axis.into(), m.z_axis.into(), m.w_axis.into())
+    }
+}
+
+impl From<Matrix4x4> for glam::Mat4 {
+    fn from(m: Matrix4x4) -> glam::Mat4 {
+        glam::Mat4::from_cols(m.column(0).into(), m.column(1).into(), m.column(2).into(), m.column(3).into())
+    }
+}
@@ -0,0 +1,121 @@
+use mint;
+
+use { Vector2, Vector3, Vector4, Quaternion, Matrix3x3, Matrix4x4 };
+
+impl From<mint::Vector2<f32>> for Vector2 {
+    fn from(v: mint::Vector2<f32>) -> Vector2 {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2> for mint::Vector2<f32> {
+    fn from(v: Vector2) -> mint::Vector2<f32> {
+        mint::Vector2 { x: v.x, y: v.y }
+    }
+}
+
+impl From<mint::Vector3<f32>> for Vector3 {
+    fn from(v: mint::Vector3<f32>) -> Vector3 {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for mint::Vector3<f32> {
+    fn from(v: Vector3) -> mint::Vector3<f32> {
+        mint::Vector3 { x: v.x, y: v.y, z: v.z }
+    }
+}
+
+impl From<mint::Vector4<f32>> for Vector4 {
+    fn from(v: mint::Vector4<f32>) -> Vector4 {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4> for mint::Vector4<f32> {
+    fn from(v: Vector4) -> mint::Vector4<f32> {
+        mint::Vector4 { x: v.x, y: v.y, z: v.z, w: v.w }
+    }
+}
+
+impl From<mint::Quaternion<f32>> for Quaternion {
+    fn from(q: mint::Quaternion<f32>) -> Quaternion {
+        Quaternion::new(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+impl From<Quaternion> for mint::Quaternion<f32> {
+    fn from(q: Quaternion) -> mint::Quaternion<f32> {
+        mint::Quaternion {
+            v: mint::Vector3 { x: q.x, y: q.y, z: q.z },
+            s: q.w
+        }
+    }
+}
+
+// Matrices convert from both layouts, the element at a given row and column is preserved either way
+impl From<mint::ColumnMatrix3<f32>> for Matrix3x3 {
+    fn from(m: mint::ColumnMatrix3<f32>) -> Matrix3x3 {
+        Matrix3x3::new(m.x.into(), m.y.into(), m.z.into())
+    }
+}
+
+impl From<Matrix3x3> for mint::ColumnMatrix3<f32> {
+    fn from(m: Matrix3x3) -> mint::ColumnMatrix3<f32> {
+        mint::ColumnMatrix3 {
+            x: m.column(0).into(),
+            y: m.column(1).into(),
+            z: m.column(2).into()
+        }
+    }
+}
+
+impl From<mint::RowMatrix3<f32>> for Matrix3x3 {
+    fn from(m: mint::RowMatrix3<f32>) -> Matrix3x3 {
+        Matrix3x3::new(m.x.into(), m.y.into(), m.z.into()).transpose()
+    }
+}
+
+impl From<Matrix3x3> for mint::RowMatrix3<f32> {
+    fn from(m: Matrix3x3) -> mint::RowMatrix3<f32> {
+        mint::RowMatrix3 {
+            x: m.row(0).into(),
+            y: m.row(1).into(),
+            z: m.row(2).into()
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix4<f32>> for Matrix4x4 {
+    fn from(m: mint::ColumnMatrix4<f32>) -> Matrix4x4 {
+        Matrix4x4::new(m.x.into(), m.y.into(), m.z.into(), m.w.into())
+    }
+}
+
+impl From<Matrix4x4> for mint::ColumnMatrix4<f32> {
+    fn from(m: Matrix4x4) -> mint::ColumnMatrix4<f32> {
+        mint::ColumnMatrix4 {
+            x: m.column(0).into(),
+            y: m.column(1).into(),
+            z: m.column(2).into(),
+            w: m.column(3).into()
+        }
+    }
+}
+
+impl From<mint::RowMatrix4<f32>> for Matrix4x4 {
+    fn from(m: mint::RowMatrix4<f32>) -> Matrix4x4 {
+        Matrix4x4::new(m.x.into(), m.y.into(), m.z.into(), m.w.into()).transpose()
+    }
+}
+
+impl From<Matrix4x4> for mint::RowMatrix4<f32> {
+    fn from(m: Matrix4x4) -> mint::RowMatrix4<f32> {
+        mint::RowMatrix4 {
+            x: m.row(0).into(),
+            y: m.row(1).into(),
+            z: m.row(2).into(),
+            w: m.row(3).into()
+        }
+    }
+}
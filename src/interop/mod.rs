@@ -0,0 +1,9 @@
+// Conversions to and from other math libraries, each behind a feature named after the crate
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "cgmath")]
+mod cgmath;
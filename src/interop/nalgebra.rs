@@ -0,0 +1,108 @@
+use nalgebra;
+
+use { Vector2, Vector3, Vector4, Quaternion, Matrix3x3, Matrix4x4 };
+
+impl From<nalgebra::Vector2<f32>> for Vector2 {
+    fn from(v: nalgebra::Vector2<f32>) -> Vector2 {
+        Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<Vector2> for nalgebra::Vector2<f32> {
+    fn from(v: Vector2) -> nalgebra::Vector2<f32> {
+        nalgebra::Vector2::new(v.x, v.y)
+    }
+}
+
+impl From<nalgebra::Vector3<f32>> for Vector3 {
+    fn from(v: nalgebra::Vector3<f32>) -> Vector3 {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3> for nalgebra::Vector3<f32> {
+    fn from(v: Vector3) -> nalgebra::Vector3<f32> {
+        nalgebra::Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<nalgebra::Vector4<f32>> for Vector4 {
+    fn from(v: nalgebra::Vector4<f32>) -> Vector4 {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4> for nalgebra::Vector4<f32> {
+    fn from(v: Vector4) -> nalgebra::Vector4<f32> {
+        nalgebra::Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+// nalgebra's constructor takes the scalar part first
+impl From<nalgebra::Quaternion<f32>> for Quaternion {
+    fn from(q: nalgebra::Quaternion<f32>) -> Quaternion {
+        Quaternion::new(q.i, q.j, q.k, q.w)
+    }
+}
+
+impl From<Quaternion> for nalgebra::Quaternion<f32> {
+    fn from(q: Quaternion) -> nalgebra::Quaternion<f32> {
+        nalgebra::Quaternion::new(q.w, q.x, q.y, q.z)
+    }
+}
+
+impl From<nalgebra::UnitQuaternion<f32>> for Quaternion {
+    fn from(q: nalgebra::UnitQuaternion<f32>) -> Quaternion {
+        q.into_inner().into()
+    }
+}
+
+// Renormalizes, rmath quaternions are not guaranteed to be unit length
+impl From<Quaternion> for nalgebra::UnitQuaternion<f32> {
+    fn from(q: Quaternion) -> nalgebra::UnitQuaternion<f32> {
+        nalgebra::UnitQuaternion::from_quaternion(q.into())
+    }
+}
+
+// nalgebra's matrix constructors take elements row by row, the same order as rmath's fields
+impl From<nalgebra::Matrix3<f32>> for Matrix3x3 {
+    fn from(m: nalgebra::Matrix3<f32>) -> Matrix3x3 {
+        Matrix3x3 {
+            m00: m[(0, 0)], m01: m[(0, 1)], m02: m[(0, 2)],
+            m10: m[(1, 0)], m11: m[(1, 1)], m12: m[(1, 2)],
+            m20: m[(2, 0)], m21: m[(2, 1)], m22: m[(2, 2)]
+        }
+    }
+}
+
+impl From<Matrix3x3> for nalgebra::Matrix3<f32> {
+    fn from(m: Matrix3x3) -> nalgebra::Matrix3<f32> {
+        nalgebra::Matrix3::new(
+            m.m00, m.m01, m.m02,
+            m.m10, m.m11, m.m12,
+            m.m20, m.m21, m.m22
+        )
+    }
+}
+
+impl From<nalgebra::Matrix4<f32>> for Matrix4x4 {
+    fn from(m: nalgebra::Matrix4<f32>) -> Matrix4x4 {
+        Matrix4x4 {
+            m00: m[(0, 0)], m01: m[(0, 1)], m02: m[(0, 2)], m03: m[(0, 3)],
+            m10: m[(1, 0)], m11: m[(1, 1)], m12: m[(1, 2)], m13: m[(1, 3)],
+            m20: m[(2, 0)], m21: m[(2, 1)], m22: m[(2, 2)], m23: m[(2, 3)],
+            m30: m[(3, 0)], m31: m[(3, 1)], m32: m[(3, 2)], m33: m[(3, 3)]
+        }
+    }
+}
+
+impl From<Matrix4x4> for nalgebra::Matrix4<f32> {
+    fn from(m: Matrix4x4) -> nalgebra::Matrix4<f32> {
+        nalgebra::Matrix4::new(
+            m.m00, m.m01, m.m02, m.m03,
+            m.m10, m.m11, m.m12, m.m13,
+            m.m20, m.m21, m.m22, m.m23,
+            m.m30, m.m31, m.m32, m.m33
+        )
+    }
+}
@@ -1,23 +1,92 @@
-extern crate num;
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("rmath needs either the std or the libm feature for its float functions");
+
+#[cfg(any(feature = "std", test))]
+extern crate core;
+#[cfg(feature = "libm")]
+extern crate libm;
+#[cfg(feature = "swizzle")]
+#[macro_use]
+extern crate paste;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+#[cfg(any(test, feature = "proptest"))]
+extern crate proptest;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_test;
 
 #[macro_use]
 mod macros;
+mod math;
 
 #[macro_use]
 mod approx_eq;
 mod clamp;
+mod error;
+mod vector2;
 mod vector3;
 mod vector4;
 mod quaternion;
+mod dual_quaternion;
+mod matrix3x2;
+mod matrix3x3;
 mod matrix4x4;
+mod affine3;
+mod transform;
+mod ray;
+mod viewport;
+mod fixed;
+mod fixed_vector3;
+mod fixed_quaternion;
+mod mask;
+mod vector3_wide;
+#[cfg(feature = "swizzle")]
+mod swizzle;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(any(feature = "mint", feature = "glam", feature = "nalgebra", feature = "cgmath"))]
+mod interop;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
 
 pub mod consts;
+pub mod batch;
+#[cfg(any(test, feature = "proptest"))]
+pub mod strategies;
+#[cfg(feature = "bytemuck")]
+pub mod pod;
 pub use approx_eq::ApproxEq;
 pub use clamp::{Clamp, Clamp01};
+pub use error::MathError;
+pub use vector2::Vector2;
 pub use vector3::Vector3;
 pub use vector4::Vector4;
 pub use quaternion::Quaternion;
+pub use dual_quaternion::DualQuaternion;
+pub use matrix3x2::Matrix3x2;
+pub use matrix3x3::Matrix3x3;
 pub use matrix4x4::Matrix4x4;
+pub use affine3::Affine3;
+pub use transform::Transform;
+pub use ray::Ray;
+pub use viewport::{ Viewport, DepthRange, ViewportOrigin };
+pub use fixed::{ Fixed32, Fixed64 };
+pub use fixed_vector3::FixedVector3;
+pub use fixed_quaternion::FixedQuaternion;
+pub use mask::{ Mask4, Mask8 };
+pub use vector3_wide::{ Vector3x4, Vector3x8 };
 
 #[cfg(test)]
 mod tests;
\ No newline at end of file
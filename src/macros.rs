@@ -86,4 +86,159 @@ macro_rules! impl_op {
         
         impl_ref_ops! { $imp, $t, $u, $method, $o }
     };
-}
\ No newline at end of file
+}
+
+// Swizzles pick their output type from the number of components, constant components are written as 0 or 1
+#[allow(unused_macros)]
+macro_rules! swizzle_component {
+    ($self_:ident, 0) => { 0.0 };
+    ($self_:ident, 1) => { 1.0 };
+    ($self_:ident, $c:ident) => { $self_.$c };
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_swizzle {
+    ($self_:ident; $a:tt $b:tt) => {
+        paste! {
+            pub fn [<$a $b>](&$self_) -> Vector2 {
+                Vector2::new(swizzle_component!($self_, $a), swizzle_component!($self_, $b))
+            }
+        }
+    };
+
+    ($self_:ident; $a:tt $b:tt $c:tt) => {
+        paste! {
+            pub fn [<$a $b $c>](&$self_) -> Vector3 {
+                Vector3::new(swizzle_component!($self_, $a), swizzle_component!($self_, $b), swizzle_component!($self_, $c))
+            }
+        }
+    };
+
+    ($self_:ident; $a:tt $b:tt $c:tt $d:tt) => {
+        paste! {
+            pub fn [<$a $b $c $d>](&$self_) -> Vector4 {
+                Vector4::new(
+                    swizzle_component!($self_, $a),
+                    swizzle_component!($self_, $b),
+                    swizzle_component!($self_, $c),
+                    swizzle_component!($self_, $d)
+                )
+            }
+        }
+    };
+}
+
+// Generates every combination of `fields` for two to four components. The third and fourth components may
+// also be one of `extra`, which allows padding like `xy0` or `xyz1`
+#[allow(unused_macros)]
+macro_rules! impl_swizzles {
+    ($t:ty, [$($field:tt)*], [$($extra:tt)*]) => {
+        impl $t {
+            impl_swizzles!(@first self; [$($field)*]; [$($field)*]; [$($field)* $($extra)*]);
+        }
+    };
+
+    (@first $self_:ident; [$($a:tt)*]; $fields:tt; $all:tt) => {
+        $( impl_swizzles!(@second $self_; $a; $fields; $all); )*
+    };
+
+    (@second $self_:ident; $a:tt; [$($b:tt)*]; $all:tt) => {
+        $(
+            impl_swizzle!($self_; $a $b);
+            impl_swizzles!(@third $self_; $a $b; $all; $all);
+        )*
+    };
+
+    (@third $self_:ident; $a:tt $b:tt; [$($c:tt)*]; $all:tt) => {
+        $(
+            impl_swizzle!($self_; $a $b $c);
+            impl_swizzles!(@fourth $self_; $a $b $c; $all);
+        )*
+    };
+
+    (@fourth $self_:ident; $a:tt $b:tt $c:tt; [$($d:tt)*]) => {
+        $( impl_swizzle!($self_; $a $b $c $d); )*
+    };
+}
+
+// Indexing and conversions between a type and arrays, tuples and slices of its element type
+macro_rules! impl_element_conversions {
+    ($t:ident, $elem:ty, $n:expr, $tuple:ty, $($field:ident: $index:tt),*) => {
+        impl $t {
+            // Panics if the slice holds fewer than the required number of elements
+            pub fn from_slice(slice: &[$elem]) -> $t {
+                $t {
+                    $($field: slice[$index]),*
+                }
+            }
+        }
+
+        impl ::core::ops::Index<usize> for $t {
+            type Output = $elem;
+
+            fn index(&self, index: usize) -> &$elem {
+                match index {
+                    $($index => &self.$field,)*
+                    _ => panic!("{} index out of range: {}", stringify!($t), index)
+                }
+            }
+        }
+
+        impl ::core::ops::IndexMut<usize> for $t {
+            fn index_mut(&mut self, index: usize) -> &mut $elem {
+                match index {
+                    $($index => &mut self.$field,)*
+                    _ => panic!("{} index out of range: {}", stringify!($t), index)
+                }
+            }
+        }
+
+        impl From<[$elem; $n]> for $t {
+            fn from(array: [$elem; $n]) -> $t {
+                $t {
+                    $($field: array[$index]),*
+                }
+            }
+        }
+
+        impl From<$t> for [$elem; $n] {
+            fn from(value: $t) -> [$elem; $n] {
+                [$(value.$field),*]
+            }
+        }
+
+        impl From<$tuple> for $t {
+            fn from(tuple: $tuple) -> $t {
+                $t {
+                    $($field: tuple.$index),*
+                }
+            }
+        }
+
+        impl From<$t> for $tuple {
+            fn from(value: $t) -> $tuple {
+                ($(value.$field),*)
+            }
+        }
+    };
+}
+
+// Conversions between a #[repr(C)] type made only of f32 fields and arrays, tuples and slices of f32
+macro_rules! impl_array_conversions {
+    ($t:ident, $n:expr, $tuple:ty, $($field:ident: $index:tt),*) => {
+        impl_element_conversions!($t, f32, $n, $tuple, $($field: $index),*);
+
+        // The type is #[repr(C)] and only holds f32 fields, so it has the same layout as [f32; N]
+        impl AsRef<[f32]> for $t {
+            fn as_ref(&self) -> &[f32] {
+                unsafe { ::core::slice::from_raw_parts(self as *const $t as *const f32, $n) }
+            }
+        }
+
+        impl AsMut<[f32]> for $t {
+            fn as_mut(&mut self) -> &mut [f32] {
+                unsafe { ::core::slice::from_raw_parts_mut(self as *mut $t as *mut f32, $n) }
+            }
+        }
+    };
+}
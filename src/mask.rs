@@ -0,0 +1,181 @@
+use core::ops::{ BitAnd, BitOr, BitXor, Not };
+use core::fmt;
+
+// Lane masks for the wide types, produced by lane-wise comparisons and consumed by `select`. Lanes are stored as
+// all ones or all zeros so `select` is a bitwise blend the compiler can vectorize instead of a branch per lane
+macro_rules! impl_mask {
+    ($t:ident, $n:expr) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $t([u32; $n]);
+
+        #[allow(dead_code)]
+        impl $t {
+            pub const LANES: usize = $n;
+            pub const NONE: $t = $t([0; $n]);
+            pub const ALL: $t = $t([!0; $n]);
+
+            pub fn new(lanes: [bool; $n]) -> $t {
+                let mut out = [0; $n];
+                for i in 0..$n {
+                    out[i] = lane(lanes[i]);
+                }
+
+                $t(out)
+            }
+
+            pub fn splat(value: bool) -> $t {
+                $t([lane(value); $n])
+            }
+
+            pub fn lt(a: [f32; $n], b: [f32; $n]) -> $t {
+                let mut lanes = [0; $n];
+                for i in 0..$n {
+                    lanes[i] = lane(a[i] < b[i]);
+                }
+
+                $t(lanes)
+            }
+
+            pub fn le(a: [f32; $n], b: [f32; $n]) -> $t {
+                let mut lanes = [0; $n];
+                for i in 0..$n {
+                    lanes[i] = lane(a[i] <= b[i]);
+                }
+
+                $t(lanes)
+            }
+
+            pub fn gt(a: [f32; $n], b: [f32; $n]) -> $t {
+                $t::lt(b, a)
+            }
+
+            pub fn ge(a: [f32; $n], b: [f32; $n]) -> $t {
+                $t::le(b, a)
+            }
+
+            pub fn test(&self, lane: usize) -> bool {
+                self.0[lane] != 0
+            }
+
+            pub fn set(&mut self, index: usize, value: bool) {
+                self.0[index] = lane(value);
+            }
+
+            pub fn any(&self) -> bool {
+                self.0.iter().any(|&lane| lane != 0)
+            }
+
+            pub fn all(&self) -> bool {
+                self.0.iter().all(|&lane| lane != 0)
+            }
+
+            pub fn none(&self) -> bool {
+                !self.any()
+            }
+
+            // Lane i is stored in bit i
+            pub fn bitmask(&self) -> u32 {
+                let mut bits = 0;
+                for i in 0..$n {
+                    bits |= (self.0[i] & 1) << i;
+                }
+
+                bits
+            }
+
+            pub fn to_array(&self) -> [bool; $n] {
+                let mut out = [false; $n];
+                for i in 0..$n {
+                    out[i] = self.0[i] != 0;
+                }
+
+                out
+            }
+
+            pub fn select(mask: $t, a: [f32; $n], b: [f32; $n]) -> [f32; $n] {
+                let mut out = [0.0; $n];
+                for i in 0..$n {
+                    out[i] = f32::from_bits((a[i].to_bits() & mask.0[i]) | (b[i].to_bits() & !mask.0[i]));
+                }
+
+                out
+            }
+        }
+
+        impl From<[bool; $n]> for $t {
+            fn from(lanes: [bool; $n]) -> $t {
+                $t::new(lanes)
+            }
+        }
+
+        impl From<$t> for [bool; $n] {
+            fn from(mask: $t) -> [bool; $n] {
+                mask.to_array()
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{:?}", self.to_array())
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{:?}", self.to_array())
+            }
+        }
+
+        impl_op! { BitAnd,
+            fn bitand(self: $t, other: $t) -> $t {
+                let mut lanes = [0; $n];
+                for i in 0..$n {
+                    lanes[i] = self.0[i] & other.0[i];
+                }
+
+                $t(lanes)
+            }
+        }
+
+        impl_op! { BitOr,
+            fn bitor(self: $t, other: $t) -> $t {
+                let mut lanes = [0; $n];
+                for i in 0..$n {
+                    lanes[i] = self.0[i] | other.0[i];
+                }
+
+                $t(lanes)
+            }
+        }
+
+        impl_op! { BitXor,
+            fn bitxor(self: $t, other: $t) -> $t {
+                let mut lanes = [0; $n];
+                for i in 0..$n {
+                    lanes[i] = self.0[i] ^ other.0[i];
+                }
+
+                $t(lanes)
+            }
+        }
+
+        impl_op! { Not,
+            fn not(self: $t) -> $t {
+                let mut lanes = [0; $n];
+                for i in 0..$n {
+                    lanes[i] = !self.0[i];
+                }
+
+                $t(lanes)
+            }
+        }
+    };
+}
+
+// All ones for true so the lane can be used directly as a bit mask
+fn lane(value: bool) -> u32 {
+    (value as u32).wrapping_neg()
+}
+
+impl_mask!(Mask4, 4);
+impl_mask!(Mask8, 8);
@@ -0,0 +1,67 @@
+// Float functions that core does not provide, routed through libm when the feature is enabled and std otherwise.
+// The deterministic feature enables libm, whose pure software implementations give the same bits on every platform
+#[cfg(feature = "libm")]
+use libm;
+
+macro_rules! impl_unary {
+    ($($name:ident => $libm:ident),*) => {
+        $(
+            #[cfg(feature = "libm")]
+            #[inline]
+            pub fn $name(x: f32) -> f32 {
+                libm::$libm(x)
+            }
+
+            #[cfg(not(feature = "libm"))]
+            #[inline]
+            pub fn $name(x: f32) -> f32 {
+                x.$name()
+            }
+        )*
+    };
+}
+
+impl_unary!(
+    sqrt => sqrtf,
+    sin => sinf,
+    cos => cosf,
+    tan => tanf,
+    asin => asinf,
+    acos => acosf,
+    exp => expf,
+    ln => logf,
+    floor => floorf,
+    ceil => ceilf,
+    round => roundf
+);
+
+#[cfg(feature = "libm")]
+#[inline]
+pub fn atan2(y: f32, x: f32) -> f32 {
+    libm::atan2f(y, x)
+}
+
+#[cfg(not(feature = "libm"))]
+#[inline]
+pub fn atan2(y: f32, x: f32) -> f32 {
+    y.atan2(x)
+}
+
+// Fused a * b + c with a single rounding, except in deterministic mode where fused operations are not used at all
+#[cfg(feature = "deterministic")]
+#[inline]
+pub fn mul_add(a: f32, b: f32, c: f32) -> f32 {
+    a * b + c
+}
+
+#[cfg(all(feature = "libm", not(feature = "deterministic")))]
+#[inline]
+pub fn mul_add(a: f32, b: f32, c: f32) -> f32 {
+    libm::fmaf(a, b, c)
+}
+
+#[cfg(not(feature = "libm"))]
+#[inline]
+pub fn mul_add(a: f32, b: f32, c: f32) -> f32 {
+    a.mul_add(b, c)
+}
@@ -0,0 +1,201 @@
+use core::ops::{ Mul, MulAssign };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
+
+use { ApproxEq, Vector2, Vector4, Matrix4x4 };
+use math;
+
+// 2D affine transform using column vectors, three columns of two rows with the translation in the last column
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Matrix3x2 {
+  pub m00: f32, pub m01: f32, pub m02: f32,
+  pub m10: f32, pub m11: f32, pub m12: f32
+}
+
+#[allow(dead_code)]
+impl Matrix3x2 {
+    pub const ZERO: Matrix3x2 = Matrix3x2 {
+        m00: 0.0, m01: 0.0, m02: 0.0,
+        m10: 0.0, m11: 0.0, m12: 0.0
+    };
+
+    pub const IDENTITY: Matrix3x2 = Matrix3x2 {
+        m00: 1.0, m01: 0.0, m02: 0.0,
+        m10: 0.0, m11: 1.0, m12: 0.0
+    };
+
+    pub fn new(c0: Vector2, c1: Vector2, c2: Vector2) -> Matrix3x2 {
+        Matrix3x2 {
+            m00: c0.x, m01: c1.x, m02: c2.x,
+            m10: c0.y, m11: c1.y, m12: c2.y
+        }
+    }
+
+    pub fn make_from_translation(translation: Vector2) -> Matrix3x2 {
+        let mut m = Matrix3x2::IDENTITY;
+        m.m02 = translation.x;
+        m.m12 = translation.y;
+
+        m
+    }
+
+    // Counter-clockwise rotation in radians
+    pub fn make_from_rotation(angle: f32) -> Matrix3x2 {
+        let sin = math::sin(angle);
+        let cos = math::cos(angle);
+
+        Matrix3x2 {
+            m00: cos, m01: -sin, m02: 0.0,
+            m10: sin, m11: cos, m12: 0.0
+        }
+    }
+
+    pub fn make_from_scale(scale: Vector2) -> Matrix3x2 {
+        let mut m = Matrix3x2::IDENTITY;
+        m.m00 = scale.x;
+        m.m11 = scale.y;
+
+        m
+    }
+
+    // Skew angles in radians, skew_x shears along the x axis proportionally to y and vice versa
+    pub fn make_from_skew(skew_x: f32, skew_y: f32) -> Matrix3x2 {
+        let mut m = Matrix3x2::IDENTITY;
+        m.m01 = math::tan(skew_x);
+        m.m10 = math::tan(skew_y);
+
+        m
+    }
+
+    pub fn make_from_trs(translation: Vector2, rotation: f32, scale: Vector2) -> Matrix3x2 {
+        let mut m = Matrix3x2::make_from_rotation(rotation);
+
+        m.m00 *= scale.x; m.m01 *= scale.y;
+        m.m10 *= scale.x; m.m11 *= scale.y;
+
+        m.m02 = translation.x;
+        m.m12 = translation.y;
+
+        m
+    }
+
+    pub fn column(&self, index: usize) -> Vector2 {
+        match index {
+            0 => Vector2::new(self.m00, self.m10),
+            1 => Vector2::new(self.m01, self.m11),
+            2 => Vector2::new(self.m02, self.m12),
+            _ => panic!("Matrix3x2 column index out of range: {}", index)
+        }
+    }
+
+    pub fn translation(&self) -> Vector2 {
+        Vector2::new(self.m02, self.m12)
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.m00 * self.m11 - self.m01 * self.m10
+    }
+
+    // Singular matrices produce non-finite values
+    pub fn inverse(&self) -> Matrix3x2 {
+        let inv_det = 1.0 / self.determinant();
+
+        let m00 = self.m11 * inv_det;
+        let m01 = -self.m01 * inv_det;
+        let m10 = -self.m10 * inv_det;
+        let m11 = self.m00 * inv_det;
+
+        Matrix3x2 {
+            m00, m01, m02: -(m00 * self.m02 + m01 * self.m12),
+            m10, m11, m12: -(m10 * self.m02 + m11 * self.m12)
+        }
+    }
+
+    pub fn transform_point(&self, point: Vector2) -> Vector2 {
+        Vector2 {
+            x: self.m00 * point.x + self.m01 * point.y + self.m02,
+            y: self.m10 * point.x + self.m11 * point.y + self.m12
+        }
+    }
+
+    pub fn transform_vector(&self, vector: Vector2) -> Vector2 {
+        Vector2 {
+            x: self.m00 * vector.x + self.m01 * vector.y,
+            y: self.m10 * vector.x + self.m11 * vector.y
+        }
+    }
+
+    // Embeds the transform in the xy plane, leaving z untouched
+    pub fn to_matrix4x4(&self) -> Matrix4x4 {
+        Matrix4x4::new(
+            Vector4::new(self.m00, self.m10, 0.0, 0.0),
+            Vector4::new(self.m01, self.m11, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 1.0, 0.0),
+            Vector4::new(self.m02, self.m12, 0.0, 1.0)
+        )
+    }
+}
+
+impl fmt::Debug for Matrix3x2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "|{}, {}, {}|\n|{}, {}, {}|",
+            self.m00, self.m01, self.m02,
+            self.m10, self.m11, self.m12
+        )
+    }
+}
+
+impl fmt::Display for Matrix3x2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "|{}, {}, {}|\n|{}, {}, {}|",
+            self.m00, self.m01, self.m02,
+            self.m10, self.m11, self.m12
+        )
+    }
+}
+
+impl PartialEq for Matrix3x2 {
+    fn eq(&self, other: &Matrix3x2) -> bool {
+        self.approx_eq(*other)
+    }
+}
+
+impl Eq for Matrix3x2 {}
+
+impl_op! { ApproxEq,
+    fn approx_eq(self: Matrix3x2, other: Matrix3x2) -> bool {
+        self.column(0).approx_eq(other.column(0)) &&
+        self.column(1).approx_eq(other.column(1)) &&
+        self.column(2).approx_eq(other.column(2))
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Matrix3x2, other: Matrix3x2) -> Matrix3x2 {
+        Matrix3x2 {
+            m00: self.m00 * other.m00 + self.m01 * other.m10,
+            m01: self.m00 * other.m01 + self.m01 * other.m11,
+            m02: self.m00 * other.m02 + self.m01 * other.m12 + self.m02,
+            m10: self.m10 * other.m00 + self.m11 * other.m10,
+            m11: self.m10 * other.m01 + self.m11 * other.m11,
+            m12: self.m10 * other.m02 + self.m11 * other.m12 + self.m12
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Matrix3x2, other: Vector2) -> Vector2 {
+        self.transform_point(other)
+    }
+}
+
+impl_op! { MulAssign,
+    fn mul_assign(&mut self: Matrix3x2, other: Matrix3x2) {
+        *self = *self * other;
+    }
+}
@@ -0,0 +1,195 @@
+use core::ops::{ Mul, MulAssign };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
+
+use { ApproxEq, Vector3, Quaternion };
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Matrix3x3 {
+  pub m00: f32, pub m01: f32, pub m02: f32,
+  pub m10: f32, pub m11: f32, pub m12: f32,
+  pub m20: f32, pub m21: f32, pub m22: f32
+}
+
+#[allow(dead_code)]
+impl Matrix3x3 {
+    pub const ZERO: Matrix3x3 = Matrix3x3 {
+        m00: 0.0, m01: 0.0, m02: 0.0,
+        m10: 0.0, m11: 0.0, m12: 0.0,
+        m20: 0.0, m21: 0.0, m22: 0.0
+    };
+
+    pub const IDENTITY: Matrix3x3 = Matrix3x3 {
+        m00: 1.0, m01: 0.0, m02: 0.0,
+        m10: 0.0, m11: 1.0, m12: 0.0,
+        m20: 0.0, m21: 0.0, m22: 1.0
+    };
+
+    pub fn new(c0: Vector3, c1: Vector3, c2: Vector3) -> Matrix3x3 {
+        Matrix3x3 {
+            m00: c0.x, m01: c1.x, m02: c2.x,
+            m10: c0.y, m11: c1.y, m12: c2.y,
+            m20: c0.z, m21: c1.z, m22: c2.z
+        }
+    }
+
+    pub fn make_from_scale(scale: Vector3) -> Matrix3x3 {
+        let mut m = Matrix3x3::IDENTITY;
+        m.m00 = scale.x;
+        m.m11 = scale.y;
+        m.m22 = scale.z;
+
+        m
+    }
+
+    pub fn make_from_rotation(q: Quaternion) -> Matrix3x3 {
+        let xx = q.x * q.x;
+        let yy = q.y * q.y;
+        let zz = q.z * q.z;
+        let xy = q.x * q.y;
+        let xz = q.x * q.z;
+        let yz = q.y * q.z;
+        let wx = q.w * q.x;
+        let wy = q.w * q.y;
+        let wz = q.w * q.z;
+
+        Matrix3x3 {
+            m00: 1.0 - 2.0 * (yy + zz), m01: 2.0 * (xy - wz), m02: 2.0 * (xz + wy),
+            m10: 2.0 * (xy + wz), m11: 1.0 - 2.0 * (xx + zz), m12: 2.0 * (yz - wx),
+            m20: 2.0 * (xz - wy), m21: 2.0 * (yz + wx), m22: 1.0 - 2.0 * (xx + yy)
+        }
+    }
+
+    pub fn make_from_rotation_scale(rotation: Quaternion, scale: Vector3) -> Matrix3x3 {
+        let mut m = Matrix3x3::make_from_rotation(rotation);
+
+        m.m00 *= scale.x; m.m01 *= scale.y; m.m02 *= scale.z;
+        m.m10 *= scale.x; m.m11 *= scale.y; m.m12 *= scale.z;
+        m.m20 *= scale.x; m.m21 *= scale.y; m.m22 *= scale.z;
+
+        m
+    }
+
+    pub fn column(&self, index: usize) -> Vector3 {
+        match index {
+            0 => Vector3::new(self.m00, self.m10, self.m20),
+            1 => Vector3::new(self.m01, self.m11, self.m21),
+            2 => Vector3::new(self.m02, self.m12, self.m22),
+            _ => panic!("Matrix3x3 column index out of range: {}", index)
+        }
+    }
+
+    pub fn row(&self, index: usize) -> Vector3 {
+        match index {
+            0 => Vector3::new(self.m00, self.m01, self.m02),
+            1 => Vector3::new(self.m10, self.m11, self.m12),
+            2 => Vector3::new(self.m20, self.m21, self.m22),
+            _ => panic!("Matrix3x3 row index out of range: {}", index)
+        }
+    }
+
+    pub fn transpose(&self) -> Matrix3x3 {
+        Matrix3x3 {
+            m00: self.m00, m01: self.m10, m02: self.m20,
+            m10: self.m01, m11: self.m11, m12: self.m21,
+            m20: self.m02, m21: self.m12, m22: self.m22
+        }
+    }
+
+    pub fn determinant(&self) -> f32 {
+        Vector3::dot(self.column(0), Vector3::cross(self.column(1), self.column(2)))
+    }
+
+    // Singular matrices produce non-finite values
+    pub fn inverse(&self) -> Matrix3x3 {
+        let c0 = self.column(0);
+        let c1 = self.column(1);
+        let c2 = self.column(2);
+
+        let r0 = Vector3::cross(c1, c2);
+        let r1 = Vector3::cross(c2, c0);
+        let r2 = Vector3::cross(c0, c1);
+        let inv_det = 1.0 / Vector3::dot(c0, r0);
+
+        Matrix3x3 {
+            m00: r0.x * inv_det, m01: r0.y * inv_det, m02: r0.z * inv_det,
+            m10: r1.x * inv_det, m11: r1.y * inv_det, m12: r1.z * inv_det,
+            m20: r2.x * inv_det, m21: r2.y * inv_det, m22: r2.z * inv_det
+        }
+    }
+}
+
+impl fmt::Debug for Matrix3x3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "|{}, {}, {}|\n|{}, {}, {}|\n|{}, {}, {}|",
+            self.m00, self.m01, self.m02,
+            self.m10, self.m11, self.m12,
+            self.m20, self.m21, self.m22
+        )
+    }
+}
+
+impl fmt::Display for Matrix3x3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "|{}, {}, {}|\n|{}, {}, {}|\n|{}, {}, {}|",
+            self.m00, self.m01, self.m02,
+            self.m10, self.m11, self.m12,
+            self.m20, self.m21, self.m22
+        )
+    }
+}
+
+impl PartialEq for Matrix3x3 {
+    fn eq(&self, other: &Matrix3x3) -> bool {
+        self.approx_eq(*other)
+    }
+}
+
+impl Eq for Matrix3x3 {}
+
+impl_op! { ApproxEq,
+    fn approx_eq(self: Matrix3x3, other: Matrix3x3) -> bool {
+        self.column(0).approx_eq(other.column(0)) &&
+        self.column(1).approx_eq(other.column(1)) &&
+        self.column(2).approx_eq(other.column(2))
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Matrix3x3, other: Matrix3x3) -> Matrix3x3 {
+        let r0 = self.row(0);
+        let r1 = self.row(1);
+        let r2 = self.row(2);
+
+        let c0 = other.column(0);
+        let c1 = other.column(1);
+        let c2 = other.column(2);
+
+        Matrix3x3 {
+            m00: Vector3::dot(r0, c0), m01: Vector3::dot(r0, c1), m02: Vector3::dot(r0, c2),
+            m10: Vector3::dot(r1, c0), m11: Vector3::dot(r1, c1), m12: Vector3::dot(r1, c2),
+            m20: Vector3::dot(r2, c0), m21: Vector3::dot(r2, c1), m22: Vector3::dot(r2, c2)
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Matrix3x3, other: Vector3) -> Vector3 {
+        Vector3 {
+            x: self.m00 * other.x + self.m01 * other.y + self.m02 * other.z,
+            y: self.m10 * other.x + self.m11 * other.y + self.m12 * other.z,
+            z: self.m20 * other.x + self.m21 * other.y + self.m22 * other.z
+        }
+    }
+}
+
+impl_op! { MulAssign,
+    fn mul_assign(&mut self: Matrix3x3, other: Matrix3x3) {
+        *self = *self * other;
+    }
+}
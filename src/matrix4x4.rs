@@ -1,8 +1,10 @@
-use std::ops::{ Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign };
-use std::cmp::{ PartialEq, Eq };
-use std::fmt;
+use core::ops::{ Mul, MulAssign, Index, IndexMut };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
 
-use { ApproxEq, Vector4, Quaternion };
+use { ApproxEq, MathError, Vector3, Vector4, Quaternion, Matrix3x3 };
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+use simd;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -15,21 +17,21 @@ pub struct Matrix4x4 {
 
 #[allow(dead_code)]
 impl Matrix4x4 {
-    const ZERO: Matrix4x4 = Matrix4x4 {
+    pub const ZERO: Matrix4x4 = Matrix4x4 {
         m00: 0.0, m01: 0.0, m02: 0.0, m03: 0.0,
         m10: 0.0, m11: 0.0, m12: 0.0, m13: 0.0,
         m20: 0.0, m21: 0.0, m22: 0.0, m23: 0.0,
         m30: 0.0, m31: 0.0, m32: 0.0, m33: 0.0
     };
 
-    const IDENTITY: Matrix4x4 = Matrix4x4 {
+    pub const IDENTITY: Matrix4x4 = Matrix4x4 {
         m00: 1.0, m01: 0.0, m02: 0.0, m03: 0.0,
         m10: 0.0, m11: 1.0, m12: 0.0, m13: 0.0,
         m20: 0.0, m21: 0.0, m22: 1.0, m23: 0.0,
         m30: 0.0, m31: 0.0, m32: 0.0, m33: 1.0
     };
 
-    fn new(c0: Vector4, c1: Vector4, c2: Vector4, c3: Vector4) -> Matrix4x4 {
+    pub fn new(c0: Vector4, c1: Vector4, c2: Vector4, c3: Vector4) -> Matrix4x4 {
         Matrix4x4 {
             m00: c0.x, m01: c1.x, m02: c2.x, m03: c3.x,
             m10: c0.y, m11: c1.y, m12: c2.y, m13: c3.y,
@@ -38,41 +40,383 @@ impl Matrix4x4 {
         }
     }
 
-    fn make_from_trs() -> Matrix4x4 {
-        unimplemented!();
+    pub fn make_from_translation(translation: Vector3) -> Matrix4x4 {
+        let mut m = Matrix4x4::IDENTITY;
+        m.m03 = translation.x;
+        m.m13 = translation.y;
+        m.m23 = translation.z;
+
+        m
     }
 
-    fn make_from_rotation(q: Quaternion) -> Matrix4x4 {
-        unimplemented!();
+    pub fn make_from_scale(scale: Vector3) -> Matrix4x4 {
+        let mut m = Matrix4x4::IDENTITY;
+        m.m00 = scale.x;
+        m.m11 = scale.y;
+        m.m22 = scale.z;
+
+        m
+    }
+
+    pub fn make_from_trs(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Matrix4x4 {
+        let mut m = Matrix4x4::from(Matrix3x3::make_from_rotation_scale(rotation, scale));
+
+        m.m03 = translation.x;
+        m.m13 = translation.y;
+        m.m23 = translation.z;
+
+        m
+    }
+
+    pub fn make_from_rotation(q: Quaternion) -> Matrix4x4 {
+        Matrix4x4::from(Matrix3x3::make_from_rotation(q))
+    }
+
+    pub fn column(&self, index: usize) -> Vector4 {
+        match index {
+            0 => Vector4::new(self.m00, self.m10, self.m20, self.m30),
+            1 => Vector4::new(self.m01, self.m11, self.m21, self.m31),
+            2 => Vector4::new(self.m02, self.m12, self.m22, self.m32),
+            3 => Vector4::new(self.m03, self.m13, self.m23, self.m33),
+            _ => panic!("Matrix4x4 column index out of range: {}", index)
+        }
+    }
+
+    pub fn row(&self, index: usize) -> Vector4 {
+        match index {
+            0 => Vector4::new(self.m00, self.m01, self.m02, self.m03),
+            1 => Vector4::new(self.m10, self.m11, self.m12, self.m13),
+            2 => Vector4::new(self.m20, self.m21, self.m22, self.m23),
+            3 => Vector4::new(self.m30, self.m31, self.m32, self.m33),
+            _ => panic!("Matrix4x4 row index out of range: {}", index)
+        }
+    }
+
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    pub fn transpose(&self) -> Matrix4x4 {
+        Matrix4x4 {
+            m00: self.m00, m01: self.m10, m02: self.m20, m03: self.m30,
+            m10: self.m01, m11: self.m11, m12: self.m21, m13: self.m31,
+            m20: self.m02, m21: self.m12, m22: self.m22, m23: self.m32,
+            m30: self.m03, m31: self.m13, m32: self.m23, m33: self.m33
+        }
+    }
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    pub fn transpose(&self) -> Matrix4x4 {
+        simd::transpose(self)
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let s0 = self.m00 * self.m11 - self.m10 * self.m01;
+        let s1 = self.m00 * self.m12 - self.m10 * self.m02;
+        let s2 = self.m00 * self.m13 - self.m10 * self.m03;
+        let s3 = self.m01 * self.m12 - self.m11 * self.m02;
+        let s4 = self.m01 * self.m13 - self.m11 * self.m03;
+        let s5 = self.m02 * self.m13 - self.m12 * self.m03;
+
+        let c5 = self.m22 * self.m33 - self.m32 * self.m23;
+        let c4 = self.m21 * self.m33 - self.m31 * self.m23;
+        let c3 = self.m21 * self.m32 - self.m31 * self.m22;
+        let c2 = self.m20 * self.m33 - self.m30 * self.m23;
+        let c1 = self.m20 * self.m32 - self.m30 * self.m22;
+        let c0 = self.m20 * self.m31 - self.m30 * self.m21;
+
+        s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+    }
+
+    // General inverse, singular matrices produce non-finite values. The SIMD version rounds differently so
+    // deterministic builds always use the scalar one
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64", not(feature = "deterministic"))))]
+    pub fn inverse(&self) -> Matrix4x4 {
+        self.cofactor_inverse()
+    }
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64", not(feature = "deterministic")))]
+    pub fn inverse(&self) -> Matrix4x4 {
+        simd::inverse(self)
+    }
+
+    // Fails on non-finite input and when the inverse does not exist or overflows. There is no threshold on the
+    // determinant since it scales with the matrix, a tiny but non-zero determinant can still be a valid transform
+    pub fn try_inverse(&self) -> Result<Matrix4x4, MathError> {
+        if !self.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+
+        if self.determinant() == 0.0 {
+            return Err(MathError::Singular);
+        }
+
+        let inverse = self.inverse();
+        if !inverse.is_finite() {
+            return Err(MathError::Singular);
+        }
+
+        Ok(inverse)
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.as_ref().iter().all(|x| x.is_finite())
+    }
+
+    // Scalar inverse by cofactor expansion, kept in SIMD builds as the reference for the SIMD path
+    pub(crate) fn cofactor_inverse(&self) -> Matrix4x4 {
+        let s0 = self.m00 * self.m11 - self.m10 * self.m01;
+        let s1 = self.m00 * self.m12 - self.m10 * self.m02;
+        let s2 = self.m00 * self.m13 - self.m10 * self.m03;
+        let s3 = self.m01 * self.m12 - self.m11 * self.m02;
+        let s4 = self.m01 * self.m13 - self.m11 * self.m03;
+        let s5 = self.m02 * self.m13 - self.m12 * self.m03;
+
+        let c5 = self.m22 * self.m33 - self.m32 * self.m23;
+        let c4 = self.m21 * self.m33 - self.m31 * self.m23;
+        let c3 = self.m21 * self.m32 - self.m31 * self.m22;
+        let c2 = self.m20 * self.m33 - self.m30 * self.m23;
+        let c1 = self.m20 * self.m32 - self.m30 * self.m22;
+        let c0 = self.m20 * self.m31 - self.m30 * self.m21;
+
+        let inv_det = 1.0 / (s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0);
+
+        Matrix4x4 {
+            m00: ( self.m11 * c5 - self.m12 * c4 + self.m13 * c3) * inv_det,
+            m01: (-self.m01 * c5 + self.m02 * c4 - self.m03 * c3) * inv_det,
+            m02: ( self.m31 * s5 - self.m32 * s4 + self.m33 * s3) * inv_det,
+            m03: (-self.m21 * s5 + self.m22 * s4 - self.m23 * s3) * inv_det,
+
+            m10: (-self.m10 * c5 + self.m12 * c2 - self.m13 * c1) * inv_det,
+            m11: ( self.m00 * c5 - self.m02 * c2 + self.m03 * c1) * inv_det,
+            m12: (-self.m30 * s5 + self.m32 * s2 - self.m33 * s1) * inv_det,
+            m13: ( self.m20 * s5 - self.m22 * s2 + self.m23 * s1) * inv_det,
+
+            m20: ( self.m10 * c4 - self.m11 * c2 + self.m13 * c0) * inv_det,
+            m21: (-self.m00 * c4 + self.m01 * c2 - self.m03 * c0) * inv_det,
+            m22: ( self.m30 * s4 - self.m31 * s2 + self.m33 * s0) * inv_det,
+            m23: (-self.m20 * s4 + self.m21 * s2 - self.m23 * s0) * inv_det,
+
+            m30: (-self.m10 * c3 + self.m11 * c1 - self.m12 * c0) * inv_det,
+            m31: ( self.m00 * c3 - self.m01 * c1 + self.m02 * c0) * inv_det,
+            m32: (-self.m30 * s3 + self.m31 * s1 - self.m32 * s0) * inv_det,
+            m33: ( self.m20 * s3 - self.m21 * s1 + self.m22 * s0) * inv_det
+        }
+    }
+
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        Vector3 {
+            x: self.m00 * point.x + self.m01 * point.y + self.m02 * point.z + self.m03,
+            y: self.m10 * point.x + self.m11 * point.y + self.m12 * point.z + self.m13,
+            z: self.m20 * point.x + self.m21 * point.y + self.m22 * point.z + self.m23
+        }
+    }
+
+    pub fn transform_direction(&self, direction: Vector3) -> Vector3 {
+        Vector3 {
+            x: self.m00 * direction.x + self.m01 * direction.y + self.m02 * direction.z,
+            y: self.m10 * direction.x + self.m11 * direction.y + self.m12 * direction.z,
+            z: self.m20 * direction.x + self.m21 * direction.y + self.m22 * direction.z
+        }
+    }
+
+    // Elements in memory order, row by row. Panics if the slice holds fewer than 16 elements
+    pub fn from_slice(slice: &[f32]) -> Matrix4x4 {
+        let mut m = Matrix4x4::ZERO;
+        m.as_mut().copy_from_slice(&slice[..16]);
+
+        m
+    }
+}
+
+// Conversions, arrays follow the memory order of the fields which is row by row
+impl Index<(usize, usize)> for Matrix4x4 {
+    type Output = f32;
+
+    fn index(&self, (row, column): (usize, usize)) -> &f32 {
+        if row > 3 || column > 3 {
+            panic!("Matrix4x4 index out of range: ({}, {})", row, column);
+        }
+
+        &self.as_ref()[row * 4 + column]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix4x4 {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut f32 {
+        if row > 3 || column > 3 {
+            panic!("Matrix4x4 index out of range: ({}, {})", row, column);
+        }
+
+        &mut self.as_mut()[row * 4 + column]
+    }
+}
+
+impl From<[f32; 16]> for Matrix4x4 {
+    fn from(array: [f32; 16]) -> Matrix4x4 {
+        Matrix4x4::from_slice(&array)
+    }
+}
+
+impl From<Matrix4x4> for [f32; 16] {
+    fn from(m: Matrix4x4) -> [f32; 16] {
+        let mut array = [0.0; 16];
+        array.copy_from_slice(m.as_ref());
+
+        array
+    }
+}
+
+impl From<[[f32; 4]; 4]> for Matrix4x4 {
+    fn from(rows: [[f32; 4]; 4]) -> Matrix4x4 {
+        Matrix4x4 {
+            m00: rows[0][0], m01: rows[0][1], m02: rows[0][2], m03: rows[0][3],
+            m10: rows[1][0], m11: rows[1][1], m12: rows[1][2], m13: rows[1][3],
+            m20: rows[2][0], m21: rows[2][1], m22: rows[2][2], m23: rows[2][3],
+            m30: rows[3][0], m31: rows[3][1], m32: rows[3][2], m33: rows[3][3]
+        }
+    }
+}
+
+impl From<Matrix4x4> for [[f32; 4]; 4] {
+    fn from(m: Matrix4x4) -> [[f32; 4]; 4] {
+        [
+            [m.m00, m.m01, m.m02, m.m03],
+            [m.m10, m.m11, m.m12, m.m13],
+            [m.m20, m.m21, m.m22, m.m23],
+            [m.m30, m.m31, m.m32, m.m33]
+        ]
+    }
+}
+
+// Embeds a linear map, the translation is zero and the last row is (0, 0, 0, 1)
+impl From<Matrix3x3> for Matrix4x4 {
+    fn from(l: Matrix3x3) -> Matrix4x4 {
+        Matrix4x4 {
+            m00: l.m00, m01: l.m01, m02: l.m02, m03: 0.0,
+            m10: l.m10, m11: l.m11, m12: l.m12, m13: 0.0,
+            m20: l.m20, m21: l.m21, m22: l.m22, m23: 0.0,
+            m30: 0.0, m31: 0.0, m32: 0.0, m33: 1.0
+        }
+    }
+}
+
+// Tuples of columns, matching Matrix4x4::new
+impl From<(Vector4, Vector4, Vector4, Vector4)> for Matrix4x4 {
+    fn from((c0, c1, c2, c3): (Vector4, Vector4, Vector4, Vector4)) -> Matrix4x4 {
+        Matrix4x4::new(c0, c1, c2, c3)
+    }
+}
+
+impl From<Matrix4x4> for (Vector4, Vector4, Vector4, Vector4) {
+    fn from(m: Matrix4x4) -> (Vector4, Vector4, Vector4, Vector4) {
+        (m.column(0), m.column(1), m.column(2), m.column(3))
+    }
+}
+
+// The type is #[repr(C)] and only holds f32 fields, so it has the same layout as [f32; 16]
+impl AsRef<[f32]> for Matrix4x4 {
+    fn as_ref(&self) -> &[f32] {
+        unsafe { ::core::slice::from_raw_parts(self as *const Matrix4x4 as *const f32, 16) }
+    }
+}
+
+impl AsMut<[f32]> for Matrix4x4 {
+    fn as_mut(&mut self) -> &mut [f32] {
+        unsafe { ::core::slice::from_raw_parts_mut(self as *mut Matrix4x4 as *mut f32, 16) }
     }
 }
 
 impl fmt::Debug for Matrix4x4 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
-            "|{}, {}, {}, {}|
-            |{}, {}, {}, {}|
-            |{}, {}, {}, {}|
-            |{}, {}, {}, {}|"
-            m00, m01, m02, m03,
-            m10, m11, m12, m13,
-            m20, m21, m22, m23,
-            m30, m31, m32, m33
-        );
+            f,
+            "|{}, {}, {}, {}|\n|{}, {}, {}, {}|\n|{}, {}, {}, {}|\n|{}, {}, {}, {}|",
+            self.m00, self.m01, self.m02, self.m03,
+            self.m10, self.m11, self.m12, self.m13,
+            self.m20, self.m21, self.m22, self.m23,
+            self.m30, self.m31, self.m32, self.m33
+        )
     }
 }
 
 impl fmt::Display for Matrix4x4 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
-            "|{}, {}, {}, {}|
-            |{}, {}, {}, {}|
-            |{}, {}, {}, {}|
-            |{}, {}, {}, {}|"
-            m00, m01, m02, m03,
-            m10, m11, m12, m13,
-            m20, m21, m22, m23,
-            m30, m31, m32, m33
-        );
-    }
-}
\ No newline at end of file
+            f,
+            "|{}, {}, {}, {}|\n|{}, {}, {}, {}|\n|{}, {}, {}, {}|\n|{}, {}, {}, {}|",
+            self.m00, self.m01, self.m02, self.m03,
+            self.m10, self.m11, self.m12, self.m13,
+            self.m20, self.m21, self.m22, self.m23,
+            self.m30, self.m31, self.m32, self.m33
+        )
+    }
+}
+
+impl PartialEq for Matrix4x4 {
+    fn eq(&self, other: &Matrix4x4) -> bool {
+        self.approx_eq(*other)
+    }
+}
+
+impl Eq for Matrix4x4 {}
+
+impl_op! { ApproxEq,
+    fn approx_eq(self: Matrix4x4, other: Matrix4x4) -> bool {
+        self.column(0).approx_eq(other.column(0)) &&
+        self.column(1).approx_eq(other.column(1)) &&
+        self.column(2).approx_eq(other.column(2)) &&
+        self.column(3).approx_eq(other.column(3))
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl_op! { Mul,
+    fn mul(self: Matrix4x4, other: Matrix4x4) -> Matrix4x4 {
+        let r0 = self.row(0);
+        let r1 = self.row(1);
+        let r2 = self.row(2);
+        let r3 = self.row(3);
+
+        let c0 = other.column(0);
+        let c1 = other.column(1);
+        let c2 = other.column(2);
+        let c3 = other.column(3);
+
+        Matrix4x4 {
+            m00: Vector4::dot(r0, c0), m01: Vector4::dot(r0, c1), m02: Vector4::dot(r0, c2), m03: Vector4::dot(r0, c3),
+            m10: Vector4::dot(r1, c0), m11: Vector4::dot(r1, c1), m12: Vector4::dot(r1, c2), m13: Vector4::dot(r1, c3),
+            m20: Vector4::dot(r2, c0), m21: Vector4::dot(r2, c1), m22: Vector4::dot(r2, c2), m23: Vector4::dot(r2, c3),
+            m30: Vector4::dot(r3, c0), m31: Vector4::dot(r3, c1), m32: Vector4::dot(r3, c2), m33: Vector4::dot(r3, c3)
+        }
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl_op! { Mul,
+    fn mul(self: Matrix4x4, other: Matrix4x4) -> Matrix4x4 {
+        simd::matrix_mul(&self, &other)
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl_op! { Mul,
+    fn mul(self: Matrix4x4, other: Vector4) -> Vector4 {
+        Vector4 {
+            x: Vector4::dot(self.row(0), other),
+            y: Vector4::dot(self.row(1), other),
+            z: Vector4::dot(self.row(2), other),
+            w: Vector4::dot(self.row(3), other)
+        }
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl_op! { Mul,
+    fn mul(self: Matrix4x4, other: Vector4) -> Vector4 {
+        simd::matrix_mul_vector(&self, other)
+    }
+}
+
+impl_op! { MulAssign,
+    fn mul_assign(&mut self: Matrix4x4, other: Matrix4x4) {
+        *self = *self * other;
+    }
+}
@@ -0,0 +1,58 @@
+use core::mem;
+
+use bytemuck::{ self, Pod, Zeroable };
+
+use { Vector2, Vector3, Vector4, Quaternion, DualQuaternion, Matrix3x2, Matrix3x3, Matrix4x4, Affine3 };
+
+// Public but unnameable outside the crate, so as_floats only accepts the types below and not any Pod
+mod sealed {
+    pub trait Floats: ::bytemuck::Pod {}
+}
+
+// Every type here is #[repr(C)] and made only of f32 fields, so it has no padding and any bit pattern is valid
+macro_rules! impl_pod {
+    ($($t:ident: $n:expr),*) => {
+        $(
+            unsafe impl Zeroable for $t {}
+            unsafe impl Pod for $t {}
+            impl sealed::Floats for $t {}
+
+            const _: () = assert!(mem::size_of::<$t>() == $n * mem::size_of::<f32>());
+            const _: () = assert!(mem::align_of::<$t>() == mem::align_of::<f32>());
+        )*
+    };
+}
+
+impl_pod!(
+    Vector2: 2,
+    Vector3: 3,
+    Vector4: 4,
+    Quaternion: 4,
+    DualQuaternion: 8,
+    Matrix3x2: 6,
+    Matrix3x3: 9,
+    Matrix4x4: 16,
+    Affine3: 12
+);
+
+pub fn as_bytes<T: Pod>(values: &[T]) -> &[u8] {
+    bytemuck::cast_slice(values)
+}
+
+pub fn as_bytes_mut<T: Pod>(values: &mut [T]) -> &mut [u8] {
+    bytemuck::cast_slice_mut(values)
+}
+
+// Panics if the bytes are not aligned for T or their length is not a multiple of its size
+pub fn from_bytes<T: Pod>(bytes: &[u8]) -> &[T] {
+    bytemuck::cast_slice(bytes)
+}
+
+pub fn try_from_bytes<T: Pod>(bytes: &[u8]) -> Option<&[T]> {
+    bytemuck::try_cast_slice(bytes).ok()
+}
+
+// Views a slice of math types as the flat f32 components, e.g. for a vertex buffer of Vector3s
+pub fn as_floats<T: sealed::Floats>(values: &[T]) -> &[f32] {
+    bytemuck::cast_slice(values)
+}
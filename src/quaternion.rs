@@ -1,14 +1,21 @@
-use std::ops::{ Add, Sub, Mul, AddAssign, SubAssign, MulAssign };
-use std::cmp::{ PartialEq, Eq };
-use std::fmt;
+use core::ops::{ Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
 
-use {ApproxEq, Clamp01};
+use {ApproxEq, Clamp01, MathError};
 use consts::{ EPSILON, PI };
 use Vector3;
+use math;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+use simd;
 
 const SIN_45: f32 = 0.8509035;
 const COS_45: f32 = 0.5253219;
 
+// to_euler snaps to a pole once x * y + z * w is this close to half the squared magnitude, about 0.1 degrees
+// of pitch away from it
+const POLE_TEST: f32 = 0.5 - 1e-6;
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct Quaternion {
@@ -32,10 +39,10 @@ impl Quaternion {
     
     pub fn new(x: f32, y: f32, z: f32, w: f32) -> Quaternion {
         Quaternion {
-            x: x,
-            y: y,
-            z: z,
-            w: w
+            x,
+            y,
+            z,
+            w
         }
     }
 
@@ -44,8 +51,70 @@ impl Quaternion {
     }
 
     pub fn from_orientation(forward: Vector3, up: Vector3) -> Quaternion {
+        Quaternion::look_rotation(forward, up)
+    }
+
+    pub fn try_from_orientation(forward: Vector3, up: Vector3) -> Result<Quaternion, MathError> {
+        Quaternion::try_look_rotation(forward, up)
+    }
+
+    // Fails where look_rotation would fall back to the identity or the shortest arc
+    pub fn try_look_rotation(forward: Vector3, up: Vector3) -> Result<Quaternion, MathError> {
+        let forward = forward.try_normalized()?;
+        let up = up.try_normalized()?;
+        if Vector3::cross(up, forward).sqr_magnitude() < EPSILON {
+            return Err(MathError::DegenerateBasis);
+        }
+
+        Ok(Quaternion::look_rotation(forward, up))
+    }
+
+    pub fn try_from_to_rotation(from: Vector3, to: Vector3) -> Result<Quaternion, MathError> {
+        Ok(Quaternion::from_to_rotation(from.try_normalized()?, to.try_normalized()?))
+    }
+
+    pub fn from_to_rotation(from: Vector3, to: Vector3) -> Quaternion {
+        let from = from.normalized();
+        let to = to.normalized();
+        let dot = Vector3::dot(from, to);
+
+        if dot >= 1.0 - EPSILON {
+            return Quaternion::IDENTITY;
+        }
+
+        // Opposite vectors have no unique axis, rotate half a turn around any perpendicular
+        if dot <= -1.0 + EPSILON {
+            let mut axis = Vector3::cross(Vector3::RIGHT, from);
+            if axis.sqr_magnitude() < EPSILON {
+                axis = Vector3::cross(Vector3::UP, from);
+            }
+
+            return Quaternion::from_angle_axis(PI, axis.normalized());
+        }
+
+        let axis = Vector3::cross(from, to);
+
+        Quaternion {
+            x: axis.x,
+            y: axis.y,
+            z: axis.z,
+            w: 1.0 + dot
+        }.normalized()
+    }
+
+    pub fn look_rotation(forward: Vector3, up: Vector3) -> Quaternion {
         let forward = forward.normalized();
-        let right = Vector3::cross(up, forward).normalized();
+        if forward.sqr_magnitude() < EPSILON {
+            return Quaternion::IDENTITY;
+        }
+
+        // When forward is parallel to up the basis is undefined, fall back to the shortest arc
+        let right = Vector3::cross(up, forward);
+        if right.sqr_magnitude() < EPSILON {
+            return Quaternion::from_to_rotation(Vector3::FORWARD, forward);
+        }
+
+        let right = right.normalized();
         let up = Vector3::cross(forward, right);
         
         let m00 = right.x;
@@ -59,10 +128,10 @@ impl Quaternion {
         let m22 = forward.z;
         
         let mut q = Quaternion {
-            x: (1.0 + m00 - m11 - m22).max(0.0).sqrt() / 2.0,
-            y: (1.0 - m00 + m11 - m22).max(0.0).sqrt() / 2.0,
-            z: (1.0 - m00 - m11 + m22).max(0.0).sqrt() / 2.0,
-            w: (1.0 + m00 + m11 + m22).max(0.0).sqrt() / 2.0 
+            x: math::sqrt((1.0 + m00 - m11 - m22).max(0.0)) / 2.0,
+            y: math::sqrt((1.0 - m00 + m11 - m22).max(0.0)) / 2.0,
+            z: math::sqrt((1.0 - m00 - m11 + m22).max(0.0)) / 2.0,
+            w: math::sqrt((1.0 + m00 + m11 + m22).max(0.0)) / 2.0 
         };
 
         q.x *= (m21 - m12).signum();
@@ -86,42 +155,42 @@ impl Quaternion {
             SIN_45 * x.signum()
         }
         else {
-            x.sin()
+            math::sin(x)
         };
         
         let cos_x = if x.abs().approx_eq(90.0) {
             COS_45 * x.signum()
         }
         else {
-            x.cos()
+            math::cos(x)
         };
         
         // Yaw
-        let sin_y = y.sin();
-        let cos_y = y.cos();
+        let sin_y = math::sin(y);
+        let cos_y = math::cos(y);
         
         // Roll
-        let sin_z = z.sin();
-        let cos_z = z.cos();
-        
+        let sin_z = math::sin(z);
+        let cos_z = math::cos(z);
         
+        // Inverse of to_euler: y is applied first, then x and then z
         Quaternion {
-            x: cos_y * sin_z * cos_x - sin_y * sin_z * sin_x,
-            y: sin_y * cos_z * cos_x - sin_y * sin_z * cos_x,
-            z: cos_y * cos_z * sin_x + sin_y * cos_z * sin_x,
-            w: cos_y * cos_z * cos_x + cos_y * sin_z * sin_x
+            x: sin_y * sin_x * cos_z + cos_y * cos_x * sin_z,
+            y: sin_y * cos_x * cos_z + cos_y * sin_x * sin_z,
+            z: cos_y * sin_x * cos_z - sin_y * cos_x * sin_z,
+            w: cos_y * cos_x * cos_z - sin_y * sin_x * sin_z
         }
     }
 
     pub fn from_angle_axis(angle: f32, axis: Vector3) -> Quaternion {
         let a = angle / 2.0;
-        let sin_angle = a.sin();
+        let sin_angle = math::sin(a);
         
         Quaternion {
             x: axis.x * sin_angle,
             y: axis.y * sin_angle,
             z: axis.z * sin_angle,
-            w: a.cos()
+            w: math::cos(a)
         }
     }
     
@@ -145,26 +214,30 @@ impl Quaternion {
         
         let unit = x_sqr + y_sqr + z_sqr + w_sqr;
         let test = self.x * self.y + self.z * self.w;
-        if test > 0.5 * unit {
+
+        // At x = +-90 degrees y and z turn about the same axis and the quaternion is sqrt(0.5) * (+-s, s, +-c, c)
+        // with s, c = sin, cos((y +- z) / 2). The general terms below degrade to atan2(0, 0) there, so close to
+        // the pole the combined angle goes into y
+        if test > POLE_TEST * unit {
             return Vector3 {
                 x: PI / 2.0,
-                y: 2.0 * self.x.atan2(self.w),
+                y: 2.0 * math::atan2(self.y, self.w),
                 z: 0.0
             };
         }
         
-        if test < -0.5 * unit {
+        if test < -POLE_TEST * unit {
             return Vector3 {
                 x: -PI / 2.0,
-                y: -2.0 * self.x.atan2(self.y),
+                y: 2.0 * math::atan2(self.y, self.w),
                 z: 0.0
             };
         }
         
         Vector3 {
-            x: (2.0 * test / unit).asin(),
-            y: (2.0 * self.y * self.w - 2.0 * self.x * self.z).atan2(x_sqr - y_sqr - z_sqr + w_sqr),
-            z: (2.0 * self.x * self.w - 2.0 * self.y * self.z).atan2(-x_sqr + y_sqr - z_sqr + w_sqr)
+            x: math::asin(2.0 * test / unit),
+            y: math::atan2(2.0 * self.y * self.w - 2.0 * self.x * self.z, x_sqr - y_sqr - z_sqr + w_sqr),
+            z: math::atan2(2.0 * self.x * self.w - 2.0 * self.y * self.z, -x_sqr + y_sqr - z_sqr + w_sqr)
         }
     }
 
@@ -176,8 +249,8 @@ impl Quaternion {
             *self
         };
         
-        *out_angle = 2.0 * q.w.acos();
-        let s = (1.0 - q.w * q.w).sqrt();
+        *out_angle = 2.0 * math::acos(q.w);
+        let s = math::sqrt(1.0 - q.w * q.w);
         if s < EPSILON {
             *out_axis = Vector3 {
                 x: q.x,
@@ -220,19 +293,21 @@ impl Quaternion {
     }
     
     pub fn slerp_unclamped(from: Quaternion, to: Quaternion, t: f32) -> Quaternion {
-        let cos_half_theta = from.w * to.w + from.x * to.x + from.y * to.y + from.z * to.z;
-        if cos_half_theta >= 1.0 {
-            return from;
-        }
-        
+        // q and -q are the same rotation, negating `to` when they point into opposite hemispheres takes the shorter arc
+        let mut cos_half_theta = from.w * to.w + from.x * to.x + from.y * to.y + from.z * to.z;
         let b = if cos_half_theta < 0.0 {
-            to.inverse()
+            cos_half_theta = -cos_half_theta;
+            to * -1.0
         }
         else {
             to
         };
+
+        if cos_half_theta >= 1.0 {
+            return from;
+        }
         
-        let sin_half_theta = (1.0 - cos_half_theta * cos_half_theta).sqrt();
+        let sin_half_theta = math::sqrt(1.0 - cos_half_theta * cos_half_theta);
         if sin_half_theta.abs() < EPSILON {
             return Quaternion {
                 x: from.x * 0.5 + b.x * 0.5,
@@ -242,9 +317,9 @@ impl Quaternion {
             };
         }
         
-        let half_theta = cos_half_theta.acos();
-        let ratio_a = ((1.0 - t) * half_theta).sin() / sin_half_theta;
-        let ratio_b = (t * half_theta).sin() / sin_half_theta;
+        let half_theta = math::acos(cos_half_theta);
+        let ratio_a = math::sin((1.0 - t) * half_theta) / sin_half_theta;
+        let ratio_b = math::sin(t * half_theta) / sin_half_theta;
     
         Quaternion {
             x: from.x * ratio_a + b.x * ratio_b,
@@ -254,6 +329,119 @@ impl Quaternion {
         }
     }
 
+    pub fn rotate_towards(from: Quaternion, to: Quaternion, max_radians_delta: f32) -> Quaternion {
+        let angle = Quaternion::angle_between(from, to);
+        if angle < EPSILON {
+            return to;
+        }
+
+        let to = if Quaternion::dot(from, to) < 0.0 { to * -1.0 } else { to };
+        let t = (max_radians_delta / angle).min(1.0);
+
+        Quaternion::slerp_direct(from, to, t)
+    }
+
+    pub fn angle_between(a: Quaternion, b: Quaternion) -> f32 {
+        let dot = Quaternion::dot(a.normalized(), b.normalized()).abs();
+
+        2.0 * math::acos(dot.min(1.0))
+    }
+
+    pub fn exp(&self) -> Quaternion {
+        let v = Vector3::new(self.x, self.y, self.z);
+        let v_mag = v.magnitude();
+        let exp_w = math::exp(self.w);
+
+        if v_mag < EPSILON {
+            return Quaternion {
+                x: self.x * exp_w,
+                y: self.y * exp_w,
+                z: self.z * exp_w,
+                w: exp_w
+            };
+        }
+
+        let v = v * (math::sin(v_mag) / v_mag * exp_w);
+
+        Quaternion {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: math::cos(v_mag) * exp_w
+        }
+    }
+
+    pub fn ln(&self) -> Quaternion {
+        let v = Vector3::new(self.x, self.y, self.z);
+        let v_mag = v.magnitude();
+        let ln_mag = math::ln(self.magnitude());
+
+        if v_mag < EPSILON {
+            return Quaternion {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+                w: ln_mag
+            };
+        }
+
+        let v = v * (math::atan2(v_mag, self.w) / v_mag);
+
+        Quaternion {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: ln_mag
+        }
+    }
+
+    pub fn pow(&self, t: f32) -> Quaternion {
+        (self.ln() * t).exp()
+    }
+
+    // Spherical quadrangle interpolation between `from` and `to`. `from_tangent` and `to_tangent` are the
+    // control points produced by `squad_tangent` for each key, which keeps angular velocity continuous
+    // across keyframes when interpolating a sequence of rotations.
+    pub fn squad(from: Quaternion, to: Quaternion, from_tangent: Quaternion, to_tangent: Quaternion, t: f32) -> Quaternion {
+        let t = t.clamp01();
+        let (to, to_tangent) = if Quaternion::dot(from, to) < 0.0 {
+            (to * -1.0, to_tangent * -1.0)
+        }
+        else {
+            (to, to_tangent)
+        };
+
+        let a = Quaternion::slerp_direct(from, to, t);
+        let b = Quaternion::slerp_direct(from_tangent, to_tangent, t);
+
+        Quaternion::slerp_direct(a, b, 2.0 * t * (1.0 - t))
+    }
+
+    pub fn squad_tangent(prev: Quaternion, current: Quaternion, next: Quaternion) -> Quaternion {
+        let prev = if Quaternion::dot(prev, current) < 0.0 { prev * -1.0 } else { prev };
+        let next = if Quaternion::dot(next, current) < 0.0 { next * -1.0 } else { next };
+
+        let inv = current.inverse();
+        let sum = (inv * next).ln() + (inv * prev).ln();
+
+        current * (sum * -0.25).exp()
+    }
+
+    // Slerp without shortest path correction, squad relies on the control points keeping their hemisphere
+    fn slerp_direct(from: Quaternion, to: Quaternion, t: f32) -> Quaternion {
+        let cos_theta = Quaternion::dot(from, to).clamp(-1.0, 1.0);
+        let sin_theta = math::sqrt(1.0 - cos_theta * cos_theta);
+        if sin_theta < EPSILON {
+            return Quaternion::lerp_unclamped(from, to, t).normalized();
+        }
+
+        let theta = math::acos(cos_theta);
+        let ratio_a = math::sin((1.0 - t) * theta) / sin_theta;
+        let ratio_b = math::sin(t * theta) / sin_theta;
+
+        from * ratio_a + to * ratio_b
+    }
+
     pub fn inverse(&self) -> Quaternion {
         let sqr_norm = self.sqr_magnitude();
         
@@ -264,6 +452,32 @@ impl Quaternion {
             w: self.w / sqr_norm
         }
     }
+
+    // Scales by the largest component when the squared norm overflows or underflows, then fails like
+    // Matrix4x4::try_inverse when the inverse itself does not fit in an f32
+    pub fn try_inverse(&self) -> Result<Quaternion, MathError> {
+        if !self.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+
+        let largest = self.largest_component();
+        if largest == 0.0 {
+            return Err(MathError::Singular);
+        }
+
+        let inverse = if self.sqr_magnitude().is_normal() {
+            self.inverse()
+        }
+        else {
+            let scaled = *self / largest;
+            scaled.conjugate() / (scaled.sqr_magnitude() * largest)
+        };
+        if !inverse.is_finite() || inverse.sqr_magnitude() == 0.0 {
+            return Err(MathError::Singular);
+        }
+
+        Ok(inverse)
+    }
     
     pub fn conjugate(&self) -> Quaternion {
         Quaternion {
@@ -275,7 +489,7 @@ impl Quaternion {
     }
     
     pub fn magnitude(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
+        math::sqrt(self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w)
     }
 
     pub fn sqr_magnitude(&self) -> f32 {
@@ -283,26 +497,101 @@ impl Quaternion {
     }
     
     pub fn normalize(&mut self) {
-        let mag = self.magnitude();
-        
-        self.x = self.x / mag;
-        self.y = self.y / mag;
-        self.z = self.z / mag;
-        self.w = self.w / mag;
+        *self = self.normalized();
     }
     
+    // Quaternions too short or not finite become the identity, like FixedQuaternion::normalized. Components too
+    // large for the length are rescaled like in try_normalized
     pub fn normalized(&self) -> Quaternion {
+        self.normalized_or(Quaternion::IDENTITY)
+    }
+
+    pub fn try_normalized(&self) -> Result<Quaternion, MathError> {
+        if !self.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+
         let mag = self.magnitude();
-        
-        Quaternion {
-            x: self.x / mag,
-            y: self.y / mag,
-            z: self.z / mag,
-            w: self.w / mag
+        if mag <= EPSILON {
+            return Err(MathError::ZeroLength);
         }
+
+        // Finite components can still overflow the length, scaling by the largest one brings it back into range
+        if !mag.is_finite() {
+            let scaled = *self / self.largest_component();
+            return Ok(scaled / scaled.magnitude());
+        }
+
+        Ok(*self / mag)
+    }
+
+    pub fn normalize_or(&mut self, fallback: Quaternion) {
+        *self = self.normalized_or(fallback);
+    }
+
+    // Returns `fallback` where try_normalized would fail
+    pub fn normalized_or(&self, fallback: Quaternion) -> Quaternion {
+        self.try_normalized().unwrap_or(fallback)
+    }
+
+    // Angular velocities are world space vectors in radians per second
+    pub fn derivative(&self, angular_velocity: Vector3) -> Quaternion {
+        let omega = Quaternion {
+            x: angular_velocity.x,
+            y: angular_velocity.y,
+            z: angular_velocity.z,
+            w: 0.0
+        };
+
+        omega * *self * 0.5
+    }
+
+    pub fn integrate(&self, angular_velocity: Vector3, dt: f32) -> Quaternion {
+        (*self + self.derivative(angular_velocity) * dt).normalized()
+    }
+
+    pub fn integrate_exact(&self, angular_velocity: Vector3, dt: f32) -> Quaternion {
+        let speed = angular_velocity.magnitude();
+        if speed < EPSILON {
+            return self.normalized();
+        }
+
+        let delta = Quaternion::from_angle_axis(speed * dt, angular_velocity / speed);
+
+        (delta * *self).normalized()
+    }
+
+    pub fn angular_velocity(from: Quaternion, to: Quaternion, dt: f32) -> Vector3 {
+        let mut delta = to * from.conjugate();
+        if delta.w < 0.0 {
+            delta *= -1.0;
+        }
+
+        let log = delta.normalized().ln();
+
+        Vector3::new(log.x, log.y, log.z) * (2.0 / dt)
+    }
+
+    // Cheaper than `self * v` but only correct for a unit quaternion: v + w t + u x t with t = 2 (u x v)
+    pub fn rotate_unit(&self, v: Vector3) -> Vector3 {
+        let u = Vector3::new(self.x, self.y, self.z);
+        let t = Vector3::cross(u, v) * 2.0;
+
+        v + t * self.w + Vector3::cross(u, t)
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    fn largest_component(&self) -> f32 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs()).max(self.w.abs())
     }
 }
 
+// Conversions
+impl_array_conversions!(Quaternion, 4, (f32, f32, f32, f32), x: 0, y: 1, z: 2, w: 3);
+
 impl fmt::Debug for Quaternion {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
@@ -351,6 +640,7 @@ impl_op! { Sub,
     }
 }
 
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
 impl_op! { Mul,
     fn mul(self: Quaternion, other: Quaternion) -> Quaternion {
         Quaternion {
@@ -362,39 +652,21 @@ impl_op! { Mul,
     }
 }
 
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl_op! { Mul,
+    fn mul(self: Quaternion, other: Quaternion) -> Quaternion {
+        simd::quaternion_mul(self, other)
+    }
+}
+
+// The sandwich product q v q* written as (w^2 - u.u) v + 2 (u.v) u + 2 w (u x v) with u the vector part.
+// Like the full product it does not assume a unit quaternion, the result is scaled by the squared magnitude
 impl_op! { Mul,
     fn mul(self: Quaternion, other: Vector3) -> Vector3 {
-        let x2 = self.x * 2.0;
-        let y2 = self.y * 2.0;
-        let z2 = self.z * 2.0;
-        let w2 = self.w * 2.0;
-        
-        let xx = self.x * self.x;
-        let yy = self.y * self.y;
-        let zz = self.z * self.z;
-        let ww = self.w * self.w;
-        
-        let xx_x = xx * other.x;
-        let xx_y = xx * other.y;
-        let xx_z = xx * other.z;
-        
-        let yy_x = yy * other.x;
-        let yy_y = yy * other.y;
-        let yy_z = yy * other.z;
-        
-        let zz_x = zz * other.x;
-        let zz_y = zz * other.y;
-        let zz_z = zz * other.z;
-        
-        let ww_x = ww * other.x;
-        let ww_y = ww * other.y;
-        let ww_z = ww * other.z;
-        
-        Vector3 {
-            x: ww_x + (y2 * self.w * other.z) - (z2 * self.w * other.y) + xx_x + (y2 * self.x * other.y) + (z2 * self.x * other.z) - zz_x - yy_x,
-            y: (x2 * self.y * other.x) + yy_y + (z2 * self.y * other.z) + (w2 * self.z * other.x) - zz_y + ww_y - (x2 * self.w * other.z) - xx_y,
-            z: (x2 * self.z * other.x) + (y2 * self.z * other.y) + zz_z - (w2 * self.y * other.x) - yy_z + (w2 * self.x *other.y) - xx_z + ww_z
-        }
+        let u = Vector3::new(self.x, self.y, self.z);
+        let w = self.w;
+
+        other * (w * w - Vector3::dot(u, u)) + u * (2.0 * Vector3::dot(u, other)) + Vector3::cross(u, other) * (2.0 * w)
     }
 }
 
@@ -409,6 +681,17 @@ impl_op! { Mul,
     }
 }
     
+impl_op! { Div,
+    fn div(self: Quaternion, other: f32) -> Quaternion {
+        Quaternion {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+            w: self.w / other
+        }
+    }
+}
+
 impl_op! { AddAssign,
     fn add_assign(&mut self: Quaternion, other: Quaternion) {
         self.x = self.x + other.x;
@@ -443,4 +726,4 @@ impl_op! { MulAssign,
         self.z = self.z * other;
         self.w = self.w * other;
     }    
-}
\ No newline at end of file
+}
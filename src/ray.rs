@@ -0,0 +1,34 @@
+use core::fmt;
+
+use Vector3;
+
+#[derive(Clone, Copy)]
+pub struct Ray {
+    pub origin: Vector3,
+    pub direction: Vector3
+}
+
+impl Ray {
+    pub fn new(origin: Vector3, direction: Vector3) -> Ray {
+        Ray {
+            origin,
+            direction: direction.normalized()
+        }
+    }
+
+    pub fn get_point(&self, distance: f32) -> Vector3 {
+        self.origin + self.direction * distance
+    }
+}
+
+impl fmt::Debug for Ray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(origin: {:?}, direction: {:?})", self.origin, self.direction)
+    }
+}
+
+impl fmt::Display for Ray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(origin: {}, direction: {})", self.origin, self.direction)
+    }
+}
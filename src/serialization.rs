@@ -0,0 +1,178 @@
+use core::fmt;
+
+use serde::de::{ self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor };
+use serde::ser::{ SerializeStruct, SerializeTuple, Serializer };
+use serde::{ Deserialize, Serialize };
+
+use { Vector2, Vector3, Vector4, Quaternion, DualQuaternion, Matrix3x2, Matrix3x3, Matrix4x4, Affine3, Transform, Ray };
+use { Fixed32, Fixed64, FixedVector3, FixedQuaternion, Vector3x4, Vector3x8, Mask4, Mask8 };
+
+// Human readable formats get a struct keyed by field name, compact formats get a tuple of the fields in declaration order.
+// Deserialization accepts either shape regardless of the format
+macro_rules! impl_serde {
+    ($t:ident { $($field:ident: $ty:ty),* }) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),*];
+
+                if serializer.is_human_readable() {
+                    let mut state = serializer.serialize_struct(stringify!($t), FIELDS.len())?;
+                    $(state.serialize_field(stringify!($field), &self.$field)?;)*
+                    state.end()
+                }
+                else {
+                    let mut state = serializer.serialize_tuple(FIELDS.len())?;
+                    $(state.serialize_element(&self.$field)?;)*
+                    state.end()
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$t, D::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),*];
+
+                struct ValueVisitor;
+
+                impl<'de> Visitor<'de> for ValueVisitor {
+                    type Value = $t;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "a {} as a sequence or map of {} fields", stringify!($t), FIELDS.len())
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<$t, A::Error> {
+                        let mut index = 0;
+                        $(
+                            let $field: $ty = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(index, &self))?;
+                            index += 1;
+                        )*
+                        let _ = index;
+
+                        Ok($t { $($field),* })
+                    }
+
+                    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<$t, A::Error> {
+                        $(let mut $field: Option<$ty> = None;)*
+
+                        while let Some(key) = map.next_key_seed(FieldName(FIELDS))? {
+                            $(
+                                if key == stringify!($field) {
+                                    if $field.is_some() {
+                                        return Err(de::Error::duplicate_field(stringify!($field)));
+                                    }
+                                    $field = Some(map.next_value()?);
+                                }
+                            )*
+                        }
+
+                        Ok($t {
+                            $($field: $field.ok_or_else(|| de::Error::missing_field(stringify!($field)))?),*
+                        })
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_struct(stringify!($t), FIELDS, ValueVisitor)
+                }
+                else {
+                    deserializer.deserialize_tuple(FIELDS.len(), ValueVisitor)
+                }
+            }
+        }
+    };
+}
+
+// Fixed-point values are stored as their raw bits so a round trip is exact in every format
+macro_rules! impl_serde_bits {
+    ($t:ident, $raw:ty) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.to_bits().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$t, D::Error> {
+                <$raw>::deserialize(deserializer).map($t::from_bits)
+            }
+        }
+    };
+}
+
+// Masks are stored as one bool per lane
+macro_rules! impl_serde_mask {
+    ($t:ident, $n:expr) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.to_array().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$t, D::Error> {
+                <[bool; $n]>::deserialize(deserializer).map($t::new)
+            }
+        }
+    };
+}
+
+// Resolves a map key to one of the known field names so that owned and borrowed keys both work
+struct FieldName(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for FieldName {
+    type Value = &'static str;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<&'static str, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldName {
+    type Value = &'static str;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "one of {:?}", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<&'static str, E> {
+        match self.0.iter().find(|field| **field == value) {
+            Some(field) => Ok(field),
+            None => Err(E::unknown_field(value, self.0))
+        }
+    }
+}
+
+impl_serde!(Vector2 { x: f32, y: f32 });
+impl_serde!(Vector3 { x: f32, y: f32, z: f32 });
+impl_serde!(Vector4 { x: f32, y: f32, z: f32, w: f32 });
+impl_serde!(Quaternion { x: f32, y: f32, z: f32, w: f32 });
+impl_serde!(DualQuaternion { real: Quaternion, dual: Quaternion });
+impl_serde!(Matrix3x2 {
+    m00: f32, m01: f32, m02: f32,
+    m10: f32, m11: f32, m12: f32
+});
+impl_serde!(Matrix3x3 {
+    m00: f32, m01: f32, m02: f32,
+    m10: f32, m11: f32, m12: f32,
+    m20: f32, m21: f32, m22: f32
+});
+impl_serde!(Matrix4x4 {
+    m00: f32, m01: f32, m02: f32, m03: f32,
+    m10: f32, m11: f32, m12: f32, m13: f32,
+    m20: f32, m21: f32, m22: f32, m23: f32,
+    m30: f32, m31: f32, m32: f32, m33: f32
+});
+impl_serde!(Affine3 { linear: Matrix3x3, translation: Vector3 });
+impl_serde!(Transform { position: Vector3, rotation: Quaternion, scale: Vector3 });
+impl_serde!(Ray { origin: Vector3, direction: Vector3 });
+
+impl_serde_bits!(Fixed32, i32);
+impl_serde_bits!(Fixed64, i64);
+impl_serde!(FixedVector3 { x: Fixed64, y: Fixed64, z: Fixed64 });
+impl_serde!(FixedQuaternion { x: Fixed64, y: Fixed64, z: Fixed64, w: Fixed64 });
+
+impl_serde!(Vector3x4 { x: [f32; 4], y: [f32; 4], z: [f32; 4] });
+impl_serde!(Vector3x8 { x: [f32; 8], y: [f32; 8], z: [f32; 8] });
+impl_serde_mask!(Mask4, 4);
+impl_serde_mask!(Mask8, 8);
@@ -0,0 +1,239 @@
+// SSE kernels for the four wide types. SSE2 is part of the x86_64 baseline so no runtime detection is needed,
+// SSE4.1 is only used when the crate is built with it enabled (e.g. `-C target-feature=+sse4.1`).
+//
+// Apart from `dot` and `inverse` every kernel performs the same operations in the same order as the scalar
+// code it replaces, so results are bit for bit identical to the fallback. Those two are left out of
+// deterministic builds
+use core::arch::x86_64::*;
+
+use { Matrix4x4, Quaternion, Vector4 };
+
+macro_rules! shuffle_mask {
+    ($x:expr, $y:expr, $z:expr, $w:expr) => {
+        ($x | ($y << 2) | ($z << 4) | ($w << 6))
+    };
+}
+
+// Picks lanes x and y from `a` and lanes z and w from `b`
+macro_rules! shuffle {
+    ($a:expr, $b:expr, $x:expr, $y:expr, $z:expr, $w:expr) => {
+        _mm_shuffle_ps($a, $b, shuffle_mask!($x, $y, $z, $w))
+    };
+}
+
+macro_rules! swizzle {
+    ($v:expr, $x:expr, $y:expr, $z:expr, $w:expr) => {
+        shuffle!($v, $v, $x, $y, $z, $w)
+    };
+}
+
+macro_rules! splat {
+    ($v:expr, $i:expr) => {
+        shuffle!($v, $v, $i, $i, $i, $i)
+    };
+}
+
+#[inline(always)]
+unsafe fn load(values: &[f32]) -> __m128 {
+    debug_assert!(values.len() >= 4);
+    _mm_loadu_ps(values.as_ptr())
+}
+
+#[inline(always)]
+unsafe fn store(v: __m128, out: &mut [f32]) {
+    debug_assert!(out.len() >= 4);
+    _mm_storeu_ps(out.as_mut_ptr(), v)
+}
+
+#[inline(always)]
+unsafe fn to_vector4(v: __m128) -> Vector4 {
+    let mut out = Vector4::ZERO;
+    store(v, out.as_mut());
+    out
+}
+
+#[inline(always)]
+unsafe fn load_rows(m: &Matrix4x4) -> [__m128; 4] {
+    let values = m.as_ref();
+    [load(&values[0..]), load(&values[4..]), load(&values[8..]), load(&values[12..])]
+}
+
+#[inline(always)]
+unsafe fn from_rows(rows: [__m128; 4]) -> Matrix4x4 {
+    let mut out = Matrix4x4::IDENTITY;
+    {
+        let values = out.as_mut();
+        store(rows[0], &mut values[0..]);
+        store(rows[1], &mut values[4..]);
+        store(rows[2], &mut values[8..]);
+        store(rows[3], &mut values[12..]);
+    }
+    out
+}
+
+#[inline(always)]
+unsafe fn transpose_rows(r: [__m128; 4]) -> [__m128; 4] {
+    let t0 = _mm_unpacklo_ps(r[0], r[1]);
+    let t1 = _mm_unpacklo_ps(r[2], r[3]);
+    let t2 = _mm_unpackhi_ps(r[0], r[1]);
+    let t3 = _mm_unpackhi_ps(r[2], r[3]);
+
+    [_mm_movelh_ps(t0, t1), _mm_movehl_ps(t1, t0), _mm_movelh_ps(t2, t3), _mm_movehl_ps(t3, t2)]
+}
+
+// Vector4
+pub fn add(a: Vector4, b: Vector4) -> Vector4 {
+    unsafe { to_vector4(_mm_add_ps(load(a.as_ref()), load(b.as_ref()))) }
+}
+
+pub fn sub(a: Vector4, b: Vector4) -> Vector4 {
+    unsafe { to_vector4(_mm_sub_ps(load(a.as_ref()), load(b.as_ref()))) }
+}
+
+pub fn mul(a: Vector4, b: Vector4) -> Vector4 {
+    unsafe { to_vector4(_mm_mul_ps(load(a.as_ref()), load(b.as_ref()))) }
+}
+
+pub fn div(a: Vector4, b: Vector4) -> Vector4 {
+    unsafe { to_vector4(_mm_div_ps(load(a.as_ref()), load(b.as_ref()))) }
+}
+
+pub fn scale(a: Vector4, s: f32) -> Vector4 {
+    unsafe { to_vector4(_mm_mul_ps(load(a.as_ref()), _mm_set1_ps(s))) }
+}
+
+pub fn div_scalar(a: Vector4, s: f32) -> Vector4 {
+    unsafe { to_vector4(_mm_div_ps(load(a.as_ref()), _mm_set1_ps(s))) }
+}
+
+// The dot product instruction sums the lanes pairwise, which can differ from the scalar sum in the last bit
+#[cfg(all(target_feature = "sse4.1", not(feature = "deterministic")))]
+pub fn dot(a: Vector4, b: Vector4) -> f32 {
+    unsafe { _mm_cvtss_f32(_mm_dp_ps(load(a.as_ref()), load(b.as_ref()), 0xF1)) }
+}
+
+// Quaternion
+pub fn quaternion_mul(a: Quaternion, b: Quaternion) -> Quaternion {
+    unsafe {
+        let qa = load(a.as_ref());
+        let qb = load(b.as_ref());
+
+        let mut r = _mm_mul_ps(splat!(qa, 3), qb);
+        r = _mm_add_ps(r, _mm_mul_ps(_mm_mul_ps(splat!(qa, 0), swizzle!(qb, 3, 2, 1, 0)), _mm_setr_ps(1.0, -1.0, 1.0, -1.0)));
+        r = _mm_add_ps(r, _mm_mul_ps(_mm_mul_ps(splat!(qa, 1), swizzle!(qb, 2, 3, 0, 1)), _mm_setr_ps(1.0, 1.0, -1.0, -1.0)));
+        r = _mm_add_ps(r, _mm_mul_ps(_mm_mul_ps(splat!(qa, 2), swizzle!(qb, 1, 0, 3, 2)), _mm_setr_ps(-1.0, 1.0, 1.0, -1.0)));
+
+        let mut out = Quaternion::IDENTITY;
+        store(r, out.as_mut());
+        out
+    }
+}
+
+// Matrix4x4, rows are contiguous in memory
+pub fn matrix_mul(a: &Matrix4x4, b: &Matrix4x4) -> Matrix4x4 {
+    unsafe {
+        let ra = load_rows(a);
+        let rb = load_rows(b);
+
+        let mut out = [_mm_setzero_ps(); 4];
+        for i in 0..4 {
+            let mut r = _mm_mul_ps(splat!(ra[i], 0), rb[0]);
+            r = _mm_add_ps(r, _mm_mul_ps(splat!(ra[i], 1), rb[1]));
+            r = _mm_add_ps(r, _mm_mul_ps(splat!(ra[i], 2), rb[2]));
+            r = _mm_add_ps(r, _mm_mul_ps(splat!(ra[i], 3), rb[3]));
+            out[i] = r;
+        }
+
+        from_rows(out)
+    }
+}
+
+pub fn matrix_mul_vector(m: &Matrix4x4, v: Vector4) -> Vector4 {
+    unsafe {
+        let c = transpose_rows(load_rows(m));
+        let v = load(v.as_ref());
+
+        let mut r = _mm_mul_ps(c[0], splat!(v, 0));
+        r = _mm_add_ps(r, _mm_mul_ps(c[1], splat!(v, 1)));
+        r = _mm_add_ps(r, _mm_mul_ps(c[2], splat!(v, 2)));
+        r = _mm_add_ps(r, _mm_mul_ps(c[3], splat!(v, 3)));
+
+        to_vector4(r)
+    }
+}
+
+pub fn transpose(m: &Matrix4x4) -> Matrix4x4 {
+    unsafe { from_rows(transpose_rows(load_rows(m))) }
+}
+
+// 2x2 block helpers for the inverse, each __m128 holds a row major 2x2 matrix
+#[cfg(not(feature = "deterministic"))]
+#[inline(always)]
+unsafe fn mat2_mul(a: __m128, b: __m128) -> __m128 {
+    _mm_add_ps(_mm_mul_ps(a, swizzle!(b, 0, 3, 0, 3)), _mm_mul_ps(swizzle!(a, 1, 0, 3, 2), swizzle!(b, 2, 1, 2, 1)))
+}
+
+// adj(a) * b
+#[cfg(not(feature = "deterministic"))]
+#[inline(always)]
+unsafe fn mat2_adj_mul(a: __m128, b: __m128) -> __m128 {
+    _mm_sub_ps(_mm_mul_ps(swizzle!(a, 3, 3, 0, 0), b), _mm_mul_ps(swizzle!(a, 1, 1, 2, 2), swizzle!(b, 2, 3, 0, 1)))
+}
+
+// a * adj(b)
+#[cfg(not(feature = "deterministic"))]
+#[inline(always)]
+unsafe fn mat2_mul_adj(a: __m128, b: __m128) -> __m128 {
+    _mm_sub_ps(_mm_mul_ps(a, swizzle!(b, 3, 0, 3, 0)), _mm_mul_ps(swizzle!(a, 1, 0, 3, 2), swizzle!(b, 2, 1, 2, 1)))
+}
+
+// Block wise inverse of [A B; C D] built from 2x2 adjugates. Singular matrices produce non-finite values
+#[cfg(not(feature = "deterministic"))]
+pub fn inverse(m: &Matrix4x4) -> Matrix4x4 {
+    unsafe {
+        let r = load_rows(m);
+
+        let a = _mm_movelh_ps(r[0], r[1]);
+        let b = _mm_movehl_ps(r[1], r[0]);
+        let c = _mm_movelh_ps(r[2], r[3]);
+        let d = _mm_movehl_ps(r[3], r[2]);
+
+        // (|A|, |B|, |C|, |D|)
+        let det_sub = _mm_sub_ps(
+            _mm_mul_ps(shuffle!(r[0], r[2], 0, 2, 0, 2), shuffle!(r[1], r[3], 1, 3, 1, 3)),
+            _mm_mul_ps(shuffle!(r[0], r[2], 1, 3, 1, 3), shuffle!(r[1], r[3], 0, 2, 0, 2))
+        );
+        let det_a = splat!(det_sub, 0);
+        let det_b = splat!(det_sub, 1);
+        let det_c = splat!(det_sub, 2);
+        let det_d = splat!(det_sub, 3);
+
+        let d_c = mat2_adj_mul(d, c);
+        let a_b = mat2_adj_mul(a, b);
+
+        let mut x = _mm_sub_ps(_mm_mul_ps(det_d, a), mat2_mul(b, d_c));
+        let mut w = _mm_sub_ps(_mm_mul_ps(det_a, d), mat2_mul(c, a_b));
+        let mut y = _mm_sub_ps(_mm_mul_ps(det_b, c), mat2_mul_adj(d, a_b));
+        let mut z = _mm_sub_ps(_mm_mul_ps(det_c, b), mat2_mul_adj(a, d_c));
+
+        // |M| = |A||D| + |B||C| - tr(adj(A)B adj(D)C)
+        let mut tr = _mm_mul_ps(a_b, swizzle!(d_c, 0, 2, 1, 3));
+        tr = _mm_add_ps(tr, swizzle!(tr, 2, 3, 0, 1));
+        tr = _mm_add_ps(tr, swizzle!(tr, 1, 0, 3, 2));
+        let det = _mm_sub_ps(_mm_add_ps(_mm_mul_ps(det_a, det_d), _mm_mul_ps(det_b, det_c)), tr);
+
+        let inv_det = _mm_div_ps(_mm_setr_ps(1.0, -1.0, -1.0, 1.0), det);
+        x = _mm_mul_ps(x, inv_det);
+        y = _mm_mul_ps(y, inv_det);
+        z = _mm_mul_ps(z, inv_det);
+        w = _mm_mul_ps(w, inv_det);
+
+        // Applies the final adjugate while interleaving the blocks back into rows
+        from_rows([
+            shuffle!(x, y, 3, 1, 3, 1),
+            shuffle!(x, y, 2, 0, 2, 0),
+            shuffle!(z, w, 3, 1, 3, 1),
+            shuffle!(z, w, 2, 0, 2, 0)
+        ])
+    }
+}
@@ -0,0 +1,52 @@
+// Proptest strategies for the math types so downstream crates can reuse them in their own property tests.
+// Ranges are symmetric around zero and always finite
+use proptest::prelude::*;
+
+use consts::{ PI };
+use { Matrix4x4, Quaternion, Vector3, Vector4 };
+
+pub fn scalar(range: f32) -> impl Strategy<Value = f32> {
+    -range..range
+}
+
+pub fn vector3(range: f32) -> impl Strategy<Value = Vector3> {
+    (scalar(range), scalar(range), scalar(range)).prop_map(|(x, y, z)| Vector3::new(x, y, z))
+}
+
+pub fn vector4(range: f32) -> impl Strategy<Value = Vector4> {
+    (scalar(range), scalar(range), scalar(range), scalar(range)).prop_map(|(x, y, z, w)| Vector4::new(x, y, z, w))
+}
+
+// Vectors shorter than 0.1 are rejected so the direction is well defined
+pub fn unit_vector3() -> impl Strategy<Value = Vector3> {
+    vector3(1.0).prop_filter("too short to normalize", |v| v.magnitude() > 0.1).prop_map(|v| v.normalized())
+}
+
+// Arbitrary components, not necessarily a rotation
+pub fn quaternion(range: f32) -> impl Strategy<Value = Quaternion> {
+    (scalar(range), scalar(range), scalar(range), scalar(range)).prop_map(|(x, y, z, w)| Quaternion::new(x, y, z, w))
+}
+
+pub fn unit_quaternion() -> impl Strategy<Value = Quaternion> {
+    quaternion(1.0).prop_filter("too short to normalize", |q| q.magnitude() > 0.1).prop_map(|q| q.normalized())
+}
+
+// Angles that survive a round trip through Quaternion::to_euler, x stays away from the +-90 degree singularity
+// and y, z stay away from the +-180 degree wrap around
+pub fn euler() -> impl Strategy<Value = Vector3> {
+    let margin = 0.05;
+
+    (-PI / 2.0 + margin..PI / 2.0 - margin, -PI + margin..PI - margin, -PI + margin..PI - margin)
+        .prop_map(|(x, y, z)| Vector3::new(x, y, z))
+}
+
+// Arbitrary elements, usually but not necessarily invertible
+pub fn matrix4x4(range: f32) -> impl Strategy<Value = Matrix4x4> {
+    (vector4(range), vector4(range), vector4(range), vector4(range)).prop_map(|(c0, c1, c2, c3)| Matrix4x4::new(c0, c1, c2, c3))
+}
+
+// Translation, rotation and positive scale between 0.1 and 10, always invertible
+pub fn trs_matrix4x4() -> impl Strategy<Value = Matrix4x4> {
+    (vector3(100.0), unit_quaternion(), (0.1f32..10.0, 0.1f32..10.0, 0.1f32..10.0))
+        .prop_map(|(t, r, (sx, sy, sz))| Matrix4x4::make_from_trs(t, r, Vector3::new(sx, sy, sz)))
+}
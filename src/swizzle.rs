@@ -0,0 +1,5 @@
+use { Vector2, Vector3, Vector4 };
+
+impl_swizzles!(Vector2, [x y], [0 1]);
+impl_swizzles!(Vector3, [x y z], [0 1]);
+impl_swizzles!(Vector4, [x y z w], [0 1]);
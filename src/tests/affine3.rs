@@ -0,0 +1,82 @@
+use consts::{ DEG2RAD };
+use { Affine3, MathError, Matrix3x3, Matrix4x4, Vector3, Quaternion, ApproxEq };
+
+fn right_turn() -> Quaternion {
+    Quaternion::from_angle_axis(90.0 * DEG2RAD, Vector3::UP)
+}
+
+#[test]
+fn constants() {
+    assert_eq!(Affine3::IDENTITY.to_matrix(), Matrix4x4::IDENTITY);
+}
+
+#[test]
+fn transform_point() {
+    let a = Affine3::from_trs(Vector3::UP, right_turn(), Vector3::ONE * 2.0);
+
+    assert_approx_eq!(a.transform_point(Vector3::FORWARD), Vector3::new(2.0, 1.0, 0.0));
+    assert_approx_eq!(a * Vector3::FORWARD, Vector3::new(2.0, 1.0, 0.0));
+    assert_approx_eq!(a.transform_direction(Vector3::FORWARD), Vector3::RIGHT * 2.0);
+}
+
+#[test]
+fn compose() {
+    let a = Affine3::from_trs(Vector3::UP, right_turn(), Vector3::ONE * 2.0);
+    let b = Affine3::from_trs(Vector3::RIGHT, right_turn(), Vector3::new(1.0, 2.0, 3.0));
+    let point = Vector3::new(0.5, -1.0, 2.0);
+
+    assert!(Vector3::distance((a * b).transform_point(point), a.transform_point(b.transform_point(point))) < 1e-5);
+}
+
+#[test]
+fn inverse() {
+    let a = Affine3::from_trs(Vector3::new(1.0, 2.0, 3.0), right_turn(), Vector3::new(1.0, 2.0, 4.0));
+    let point = Vector3::new(0.5, -1.0, 2.0);
+
+    assert!(Vector3::distance(a.inverse().transform_point(a.transform_point(point)), point) < 1e-5);
+
+    let general = Affine3::from_matrix(a.to_matrix().inverse());
+    assert!(Vector3::distance(general.transform_point(point), a.inverse().transform_point(point)) < 1e-5);
+}
+
+#[test]
+fn normal_matrix() {
+    let a = Affine3::from_scale(Vector3::new(2.0, 1.0, 1.0));
+    let normal = (a.normal_matrix() * Vector3::new(1.0, 1.0, 0.0)).normalized();
+    let tangent = a.transform_direction(Vector3::new(1.0, -1.0, 0.0));
+
+    assert_approx_eq!(Vector3::dot(normal, tangent), 0.0);
+    assert_eq!(Affine3::from_rotation(right_turn()).normal_matrix(), Matrix3x3::make_from_rotation(right_turn()));
+}
+
+#[test]
+fn matrix_round_trip() {
+    let a = Affine3::from_trs(Vector3::new(1.0, 2.0, 3.0), right_turn(), Vector3::new(1.0, 2.0, 4.0));
+    let m = a.to_matrix();
+
+    assert_eq!(m, Matrix4x4::make_from_trs(Vector3::new(1.0, 2.0, 3.0), right_turn(), Vector3::new(1.0, 2.0, 4.0)));
+
+    let round_trip = Affine3::from_matrix(m);
+    assert_eq!(round_trip.linear, a.linear);
+    assert_eq!(round_trip.translation, a.translation);
+}
+
+#[test]
+fn try_from_matrix() {
+    let a = Affine3::from_trs(Vector3::new(1.0, 2.0, 3.0), right_turn(), Vector3::new(1.0, 2.0, 4.0));
+    let round_trip = Affine3::try_from_matrix(a.to_matrix()).unwrap();
+    assert_eq!(round_trip.linear, a.linear);
+    assert_eq!(round_trip.translation, a.translation);
+
+    // A projective last row has no affine equivalent, from_matrix would drop it
+    let mut m = a.to_matrix();
+    m.m32 = 1.0;
+    assert_eq!(Affine3::try_from_matrix(m).err(), Some(MathError::NotAffine));
+
+    m.m32 = 0.0;
+    m.m33 = 2.0;
+    assert_eq!(Affine3::try_from_matrix(m).err(), Some(MathError::NotAffine));
+
+    m.m00 = f32::NAN;
+    assert_eq!(Affine3::try_from_matrix(m).err(), Some(MathError::NonFinite));
+}
@@ -0,0 +1,145 @@
+use batch;
+use { Matrix4x4, Quaternion, Vector3 };
+
+// An odd number of elements so no vector width divides the input evenly
+fn points() -> Vec<Vector3> {
+    (0..19).map(|i| {
+        let i = i as f32;
+        Vector3::new(i * 0.5 - 4.0, 3.0 - i * 0.25, (i * 0.7).sin() * 5.0)
+    }).collect()
+}
+
+fn bits(v: Vector3) -> [u32; 3] {
+    [v.x.to_bits(), v.y.to_bits(), v.z.to_bits()]
+}
+
+fn transform() -> Matrix4x4 {
+    Matrix4x4::make_from_trs(
+        Vector3::new(1.0, -2.0, 3.0),
+        Quaternion::from_euler(Vector3::new(0.3, -1.2, 0.8)),
+        Vector3::new(2.0, 0.5, 1.5)
+    )
+}
+
+#[test]
+fn transform_points() {
+    let m = transform();
+    let points = points();
+
+    let mut out = vec![Vector3::ZERO; points.len()];
+    batch::transform_points(&m, &points, &mut out);
+
+    for (p, o) in points.iter().zip(out.iter()) {
+        assert_eq!(*o, m.transform_point(*p));
+    }
+}
+
+// Every prefix length matches the per element calls bit for bit
+#[test]
+fn every_length_matches_per_element() {
+    let m = transform();
+    let all = points();
+
+    for len in 0..all.len() {
+        let points = &all[..len];
+        let mut out = vec![Vector3::ZERO; len];
+        batch::transform_points(&m, points, &mut out);
+
+        let mut normalized = points.to_vec();
+        batch::normalize(&mut normalized);
+
+        for i in 0..len {
+            assert_eq!(bits(out[i]), bits(m.transform_point(points[i])));
+            assert_eq!(bits(normalized[i]), bits(points[i].normalized()));
+        }
+    }
+}
+
+#[test]
+fn transform_directions() {
+    let m = transform();
+    let directions = points();
+
+    let mut out = vec![Vector3::ZERO; directions.len()];
+    batch::transform_directions(&m, &directions, &mut out);
+
+    for (d, o) in directions.iter().zip(out.iter()) {
+        assert_eq!(*o, m.transform_direction(*d));
+    }
+}
+
+#[test]
+fn rotate_matches_quaternion_mul() {
+    let points = points();
+
+    for q in [Quaternion::from_euler(Vector3::new(0.3, -1.2, 0.8)), Quaternion::new(0.5, -1.0, 0.25, 2.0)].iter() {
+        let mut out = vec![Vector3::ZERO; points.len()];
+        batch::rotate(*q, &points, &mut out);
+
+        for (p, o) in points.iter().zip(out.iter()) {
+            let expected = q * *p;
+            assert!((*o - expected).magnitude() <= 1e-5 * expected.magnitude().max(1.0), "{} != {}", o, expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn output_length_mismatch() {
+    let points = points();
+    let mut out = vec![Vector3::ZERO; points.len() - 1];
+
+    batch::rotate(Quaternion::IDENTITY, &points, &mut out);
+}
+
+#[test]
+fn normalize() {
+    let mut vectors = points();
+    vectors[3] = Vector3::ZERO;
+    let expected: Vec<Vector3> = vectors.iter().map(|v| v.normalized()).collect();
+
+    batch::normalize(&mut vectors);
+
+    assert_eq!(vectors, expected);
+}
+
+#[test]
+fn bounds() {
+    let points = points();
+    let (min, max) = batch::bounds(&points).unwrap();
+
+    let mut expected_min = points[0];
+    let mut expected_max = points[0];
+    for p in points.iter() {
+        expected_min = Vector3::min(expected_min, *p);
+        expected_max = Vector3::max(expected_max, *p);
+    }
+
+    assert_eq!(min, expected_min);
+    assert_eq!(max, expected_max);
+
+    assert!(batch::bounds(&[]).is_none());
+    assert_eq!(batch::bounds(&points[5..6]), Some((points[5], points[5])));
+}
+
+#[test]
+fn skinning_palette() {
+    let world: Vec<Matrix4x4> = (0..5).map(|i| Matrix4x4::make_from_translation(Vector3::new(i as f32, 0.0, 0.0)) * transform()).collect();
+    let inverse_bind: Vec<Matrix4x4> = world.iter().map(|m| m.inverse()).collect();
+
+    let mut palette = vec![Matrix4x4::ZERO; world.len()];
+    batch::multiply_matrices(&world, &inverse_bind, &mut palette);
+    for (i, m) in palette.iter().enumerate() {
+        assert_eq!(*m, world[i] * inverse_bind[i]);
+        for (a, b) in m.as_ref().iter().zip(Matrix4x4::IDENTITY.as_ref()) {
+            assert!((a - b).abs() <= 1e-5);
+        }
+    }
+
+    let root = Matrix4x4::make_from_translation(Vector3::new(0.0, 10.0, 0.0));
+    let mut moved = vec![Matrix4x4::ZERO; world.len()];
+    batch::premultiply_matrices(&root, &world, &mut moved);
+    for (i, m) in moved.iter().enumerate() {
+        assert_eq!(*m, root * world[i]);
+    }
+}
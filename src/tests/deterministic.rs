@@ -0,0 +1,78 @@
+// Golden values pinning the exact bits produced in deterministic mode, these must match on every platform
+use math;
+use { Matrix4x4, Vector3, Vector4, Quaternion };
+
+#[test]
+fn transcendentals() {
+    assert_eq!(math::sin(1.234).to_bits(), 0x3f719e12);
+    assert_eq!(math::cos(1.234).to_bits(), 0x3ea932ba);
+    assert_eq!(math::tan(1.234).to_bits(), 0x4036c930);
+    assert_eq!(math::asin(0.4321).to_bits(), 0x3ee4c59a);
+    assert_eq!(math::acos(0.4321).to_bits(), 0x3f8fde74);
+    assert_eq!(math::atan2(-0.75, 2.5).to_bits(), 0xbe9539d4);
+    assert_eq!(math::exp(1.234).to_bits(), 0x405bd616);
+    assert_eq!(math::ln(1.234).to_bits(), 0x3e574ea2);
+    assert_eq!(math::sqrt(2.0).to_bits(), 0x3fb504f3);
+}
+
+#[test]
+fn mul_add_is_not_fused() {
+    // (1 + 2^-12)^2 = 1 + 2^-11 + 2^-24, the last term only survives a fused multiply-add
+    let x = 1.0 + 1.0 / 4096.0;
+
+    assert_eq!(math::mul_add(x, x, -(1.0 + 1.0 / 2048.0)), 0.0);
+
+    let v = (Vector3::ONE * x).mul_add(Vector3::ONE * x, Vector3::ONE * -(1.0 + 1.0 / 2048.0));
+    assert_eq!((v.x, v.y, v.z), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn slerp() {
+    let a = Quaternion::from_euler(Vector3::new(0.3, 1.1, -0.7));
+    let b = Quaternion::from_euler(Vector3::new(-0.9, 0.4, 0.6));
+    let q = Quaternion::slerp(a, b, 0.37);
+
+    assert_eq!(q.x.to_bits(), 0xbd980368);
+    assert_eq!(q.y.to_bits(), 0x3ea78dba);
+    assert_eq!(q.z.to_bits(), 0x3c2aa7a0);
+    assert_eq!(q.w.to_bits(), 0x3f712440);
+}
+
+#[test]
+fn to_euler() {
+    let e = Quaternion::from_euler(Vector3::new(-0.9, 0.4, 0.6)).to_euler();
+
+    assert_eq!(e.x.to_bits(), 0xbf666664);
+    assert_eq!(e.y.to_bits(), 0x3ecccccb);
+    assert_eq!(e.z.to_bits(), 0x3f19999a);
+}
+
+#[test]
+fn angle() {
+    let angle = Vector3::angle(Vector3::new(0.3, -1.7, 2.2), Vector3::new(-4.1, 0.6, 1.9));
+
+    assert_eq!(angle.to_bits(), 0x3fb59b91);
+}
+
+// SIMD builds switch to kernels that round differently for these two, deterministic builds must not
+#[test]
+fn vector4_dot() {
+    let a = Vector4::new(0.1, -1.7, 3.3, 1e-3);
+    let b = Vector4::new(7.9, 0.3, -2.1, 11.0);
+
+    assert_eq!(Vector4::dot(a, b).to_bits(), 0xc0d472af);
+}
+
+#[test]
+fn matrix_inverse() {
+    let rotation = Quaternion::from_euler(Vector3::new(0.3, 1.1, -0.7));
+    let m = Matrix4x4::make_from_trs(Vector3::new(1.5, -2.0, 0.25), rotation, Vector3::new(0.9, 1.3, 2.7));
+    let bits: Vec<u32> = m.inverse().as_ref().iter().map(|x| x.to_bits()).collect();
+
+    assert_eq!(bits, [
+        0x3ef68541, 0x3ea81e43, 0xbf722d46, 0x3e2f168c,
+        0xbf053fce, 0x3f0fe35a, 0xbd8f02bb, 0x3ff60f3e,
+        0x3e61c3ba, 0x3e6969c1, 0x3e43ec94, 0x3d9e658c,
+        0x00000000, 0x00000000, 0x00000000, 0x3f800001
+    ]);
+}
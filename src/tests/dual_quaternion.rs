@@ -0,0 +1,101 @@
+use consts::{ DEG2RAD };
+use { Vector3, Quaternion, DualQuaternion, ApproxEq };
+
+fn right_turn() -> Quaternion {
+    Quaternion::from_angle_axis(90.0 * DEG2RAD, Vector3::UP)
+}
+
+#[test]
+fn constants() {
+    assert_eq!(DualQuaternion::IDENTITY.rotation(), Quaternion::IDENTITY);
+    assert_eq!(DualQuaternion::IDENTITY.translation(), Vector3::ZERO);
+}
+
+#[test]
+fn from_rotation_translation() {
+    let dq = DualQuaternion::from_rotation_translation(right_turn(), Vector3::new(1.0, 2.0, 3.0));
+
+    assert_approx_eq!(dq.rotation(), right_turn());
+    assert!(Vector3::distance(dq.translation(), Vector3::new(1.0, 2.0, 3.0)) < 1e-5);
+}
+
+#[test]
+fn transform_point() {
+    let dq = DualQuaternion::from_rotation_translation(right_turn(), Vector3::UP);
+
+    assert_approx_eq!(dq.transform_point(Vector3::FORWARD), Vector3::new(1.0, 1.0, 0.0));
+    assert_approx_eq!(dq * Vector3::FORWARD, Vector3::new(1.0, 1.0, 0.0));
+    assert_approx_eq!(dq.transform_direction(Vector3::FORWARD), Vector3::RIGHT);
+}
+
+#[test]
+fn compose() {
+    let a = DualQuaternion::from_translation(Vector3::UP);
+    let b = DualQuaternion::from_rotation(right_turn());
+    let point = Vector3::new(1.0, 2.0, 3.0);
+
+    let composed = (a * b).transform_point(point);
+    let sequential = a.transform_point(b.transform_point(point));
+
+    assert!(Vector3::distance(composed, sequential) < 1e-5);
+}
+
+#[test]
+fn inverse() {
+    let dq = DualQuaternion::from_rotation_translation(right_turn(), Vector3::new(1.0, 2.0, 3.0));
+    let point = Vector3::new(-4.0, 5.0, 0.5);
+
+    assert!(Vector3::distance(dq.inverse().transform_point(dq.transform_point(point)), point) < 1e-5);
+    assert_approx_eq!(dq * dq.inverse(), DualQuaternion::IDENTITY);
+}
+
+#[test]
+fn normalized() {
+    let dq = DualQuaternion::from_rotation_translation(right_turn(), Vector3::new(1.0, 2.0, 3.0)) * 3.0;
+    let dq_norm = dq.normalized();
+
+    assert_approx_eq!(dq_norm.magnitude(), 1.0);
+    assert_approx_eq!(Quaternion::dot(dq_norm.real, dq_norm.dual), 0.0);
+    assert!(Vector3::distance(dq_norm.translation(), Vector3::new(1.0, 2.0, 3.0)) < 1e-5);
+}
+
+#[test]
+fn sclerp() {
+    let a = DualQuaternion::IDENTITY;
+    let b = DualQuaternion::from_rotation_translation(right_turn(), Vector3::UP * 2.0);
+
+    assert_approx_eq!(DualQuaternion::sclerp(a, b, 0.0), a);
+    assert_approx_eq!(DualQuaternion::sclerp(a, b, 1.0), b);
+
+    // Screw motion around the y axis moves linearly along it
+    let half = DualQuaternion::sclerp(a, b, 0.5);
+    assert_approx_eq!(half.rotation(), Quaternion::from_angle_axis(45.0 * DEG2RAD, Vector3::UP));
+    assert_approx_eq!(half.translation(), Vector3::UP);
+}
+
+#[test]
+fn sclerp_translation() {
+    let a = DualQuaternion::from_translation(Vector3::ZERO);
+    let b = DualQuaternion::from_translation(Vector3::RIGHT * 4.0);
+
+    assert_approx_eq!(DualQuaternion::sclerp(a, b, 0.25).translation(), Vector3::RIGHT);
+}
+
+#[test]
+fn dlb() {
+    let a = DualQuaternion::from_rotation_translation(Quaternion::IDENTITY, Vector3::RIGHT);
+    let b = DualQuaternion::from_rotation_translation(right_turn() * -1.0, Vector3::RIGHT);
+
+    let blended = DualQuaternion::dlb(&[a, b], &[0.5, 0.5]);
+
+    assert_approx_eq!(blended.magnitude(), 1.0);
+    assert_approx_eq!(blended.rotation(), Quaternion::from_angle_axis(45.0 * DEG2RAD, Vector3::UP));
+    assert_approx_eq!(blended.translation(), Vector3::RIGHT);
+    assert_approx_eq!(DualQuaternion::dlb(&[], &[]), DualQuaternion::IDENTITY);
+}
+
+#[test]
+#[should_panic]
+fn dlb_missing_weight() {
+    DualQuaternion::dlb(&[DualQuaternion::IDENTITY, DualQuaternion::IDENTITY], &[1.0]);
+}
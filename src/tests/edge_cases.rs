@@ -0,0 +1,183 @@
+// Behaviour with zero, NaN and infinite inputs as described in the edge case section of the readme
+use core::f32;
+
+use consts::{ PI };
+use { DualQuaternion, Fixed32, Fixed64, FixedQuaternion, FixedVector3, MathError, Matrix3x3, Matrix4x4, Quaternion };
+use { ApproxEq, Ray, Transform, Vector2, Vector3, Vector4, Vector3x4, Viewport };
+use batch;
+
+const NON_FINITE: [f32; 3] = [f32::NAN, f32::INFINITY, f32::NEG_INFINITY];
+
+fn is_identity(q: Quaternion) -> bool {
+    q.x == 0.0 && q.y == 0.0 && q.z == 0.0 && q.w == 1.0
+}
+
+fn is_unit(q: Quaternion) -> bool {
+    q.is_finite() && (q.magnitude() - 1.0).abs() < 1e-5
+}
+
+#[test]
+fn normalize_zero() {
+    assert_eq!(Vector2::ZERO.normalized(), Vector2::ZERO);
+    assert_eq!(Vector3::ZERO.normalized(), Vector3::ZERO);
+    assert_eq!(Vector4::ZERO.normalized(), Vector4::ZERO);
+    assert!(is_identity(Quaternion::new(0.0, 0.0, 0.0, 0.0).normalized()));
+    assert!(is_identity(Quaternion::new(1e-9, 0.0, -1e-9, 0.0).normalized()));
+
+    let mut q = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+    q.normalize();
+    assert!(is_identity(q));
+
+    let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+    assert!(is_identity(DualQuaternion::new(zero, zero).normalized().real));
+}
+
+#[test]
+fn normalize_nan() {
+    let v = Vector3::new(f32::NAN, 1.0, 0.0);
+
+    assert_eq!(v.normalized(), Vector3::ZERO);
+    assert_eq!(Vector2::new(f32::NAN, 1.0).normalized(), Vector2::ZERO);
+    assert_eq!(Vector4::new(0.0, f32::NAN, 1.0, 0.0).normalized(), Vector4::ZERO);
+    assert!(is_identity(Quaternion::new(f32::NAN, 0.0, 0.0, 1.0).normalized()));
+    assert!(is_identity(Quaternion::new(0.0, f32::INFINITY, 0.0, 1.0).normalized()));
+
+    // Finite components whose length overflows are rescaled instead
+    assert_approx_eq!(Quaternion::new(3e20, 0.0, 0.0, 4e20).normalized(), Quaternion::new(0.6, 0.0, 0.0, 0.8));
+    assert_eq!(Vector3x4::splat(v).normalized().lane(2), Vector3::ZERO);
+
+    let mut vectors = [v, Vector3::UP * 2.0];
+    batch::normalize(&mut vectors);
+    assert_eq!(vectors, [Vector3::ZERO, Vector3::UP]);
+}
+
+#[test]
+fn try_variants_reject_non_finite() {
+    for &x in NON_FINITE.iter() {
+        let v = Vector3::new(x, 1.0, 0.0);
+
+        assert_eq!(v.try_normalized(), Err(MathError::NonFinite));
+        assert_eq!(Vector4::new(x, 0.0, 0.0, 1.0).try_normalized(), Err(MathError::NonFinite));
+        assert_eq!(Quaternion::new(0.0, x, 0.0, 1.0).try_normalized(), Err(MathError::NonFinite));
+        assert_eq!(Quaternion::new(0.0, x, 0.0, 1.0).try_inverse(), Err(MathError::NonFinite));
+        assert_eq!(Quaternion::try_look_rotation(v, Vector3::UP), Err(MathError::NonFinite));
+        assert_eq!(Quaternion::try_from_to_rotation(Vector3::UP, v), Err(MathError::NonFinite));
+
+        let mut m = Matrix4x4::IDENTITY;
+        m[(1, 2)] = x;
+        assert_eq!(m.try_inverse(), Err(MathError::NonFinite));
+    }
+}
+
+#[test]
+fn inverse_of_zero() {
+    let q = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+
+    assert!(!q.inverse().is_finite());
+    assert_eq!(q.try_inverse(), Err(MathError::Singular));
+    assert!(!Matrix4x4::ZERO.inverse().is_finite());
+    assert_eq!(Matrix4x4::ZERO.try_inverse(), Err(MathError::Singular));
+    assert!(!Matrix3x3::make_from_scale(Vector3::ZERO).inverse().m00.is_finite());
+}
+
+#[test]
+fn transform_zero_scale() {
+    let t = Transform::new(Vector3::ONE, Quaternion::IDENTITY, Vector3::new(2.0, 0.0, 1.0));
+
+    assert_eq!(t.inverse().scale, Vector3::new(0.5, 0.0, 1.0));
+    assert_eq!(t.inverse_transform_point(Vector3::new(3.0, 5.0, 1.0)), Vector3::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn orientation_fallbacks() {
+    assert!(is_identity(Quaternion::look_rotation(Vector3::ZERO, Vector3::UP)));
+    assert!(is_identity(Quaternion::from_direction(Vector3::ZERO)));
+    assert!(is_identity(Quaternion::from_to_rotation(Vector3::ZERO, Vector3::RIGHT)));
+
+    // Forward parallel to up, or no up at all, still gives a rotation onto forward
+    for &up in [Vector3::UP, Vector3::UP * -3.0, Vector3::ZERO].iter() {
+        let q = Quaternion::from_orientation(Vector3::UP, up);
+
+        assert!(is_unit(q));
+        assert_eq!(q * Vector3::FORWARD, Vector3::UP);
+    }
+
+    assert_eq!(Quaternion::try_from_orientation(Vector3::UP, Vector3::UP), Err(MathError::DegenerateBasis));
+    assert_eq!(Quaternion::try_look_rotation(Vector3::ZERO, Vector3::UP), Err(MathError::ZeroLength));
+
+    let q = Quaternion::from_to_rotation(Vector3::RIGHT, Vector3::RIGHT * -1.0);
+    assert!(is_unit(q));
+    assert_eq!(q * Vector3::RIGHT, Vector3::RIGHT * -1.0);
+}
+
+#[test]
+fn angles_of_zero_vectors() {
+    assert_approx_eq!(Vector3::angle(Vector3::ZERO, Vector3::UP), PI / 2.0);
+    assert_approx_eq!(Vector2::angle(Vector2::ZERO, Vector2::ZERO), PI / 2.0);
+    assert_eq!(Quaternion::angle_between(Quaternion::new(0.0, 0.0, 0.0, 0.0), Quaternion::IDENTITY), 0.0);
+}
+
+#[test]
+fn points_at_infinity() {
+    let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+
+    assert!(Vector4::new(1.0, 2.0, 3.0, 0.0).perspective_divide().is_none());
+    assert!(viewport.project(Vector3::ZERO, Matrix4x4::ZERO).is_none());
+    assert_eq!(Ray::new(Vector3::ZERO, Vector3::ZERO).direction, Vector3::ZERO);
+}
+
+#[test]
+fn quaternion_exp_overflow() {
+    // e^100 does not fit in an f32, the exponentials are the documented exceptions to finite output
+    let q = Quaternion::new(0.5, 0.0, 0.0, 100.0);
+    assert!(!q.exp().is_finite());
+    assert!(!q.pow(20.0).is_finite());
+    assert!(Quaternion::new(0.5, 0.0, 0.0, 88.0).exp().is_finite());
+
+    assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).ln().w, f32::NEG_INFINITY);
+}
+
+#[test]
+fn non_finite_does_not_panic() {
+    for &x in NON_FINITE.iter() {
+        let v = Vector3::new(x, 0.0, 1.0);
+        let q = Quaternion::new(x, 0.0, 0.0, 1.0);
+
+        let _ = (Quaternion::look_rotation(v, Vector3::UP), Quaternion::from_to_rotation(v, Vector3::UP));
+        let _ = (Quaternion::slerp(q, Quaternion::IDENTITY, 0.5), q.to_euler(), q.ln().exp(), q * v);
+        let _ = (Vector3::rotate_towards(v, Vector3::UP, x, x), Vector3::move_towards(v, Vector3::UP, x));
+        let _ = (Matrix4x4::make_from_trs(v, q, v).inverse(), DualQuaternion::from_rotation_translation(q, v).normalized());
+        let _ = batch::bounds(&[v, Vector3::ONE]);
+    }
+}
+
+#[test]
+fn fixed_from_non_finite() {
+    assert_eq!(Fixed32::from_f32(f32::NAN), Fixed32::ZERO);
+    assert_eq!(Fixed64::from_f64(f64::NAN), Fixed64::ZERO);
+    assert_eq!(Fixed32::from_f32(f32::INFINITY), Fixed32::MAX);
+    assert_eq!(Fixed32::from_f32(f32::NEG_INFINITY), Fixed32::MIN);
+    assert_eq!(FixedVector3::from(Vector3::new(f32::NAN, 0.0, 0.0)), FixedVector3::ZERO);
+}
+
+#[test]
+fn fixed_division() {
+    assert_eq!(Fixed32::ONE.checked_div(Fixed32::ZERO), None);
+    assert_eq!(Fixed64::MAX.checked_div(Fixed64::EPSILON), None);
+    assert_eq!(FixedQuaternion::default().normalized(), FixedQuaternion::IDENTITY);
+    assert_eq!(FixedQuaternion::default().inverse(), FixedQuaternion::IDENTITY);
+    assert_eq!(FixedQuaternion::default().try_inverse(), Err(MathError::Singular));
+
+    // Fixed64::MIN wraps under abs, normalizing still finds its direction
+    let v = FixedVector3::new(Fixed64::MIN, Fixed64::MIN, Fixed64::from_int(1 << 16));
+    assert_approx_eq!(Vector3::from(v.normalized()), Vector3::new(-1.0, -1.0, 1.0 / 32768.0).normalized());
+
+    let q = FixedQuaternion::new(Fixed64::MIN, Fixed64::ZERO, Fixed64::ZERO, Fixed64::MAX);
+    assert_approx_eq!(Quaternion::from(q.normalized()), Quaternion::new(-0.70710677, 0.0, 0.0, 0.70710677));
+}
+
+#[test]
+#[should_panic]
+fn fixed_division_by_zero() {
+    let _ = Fixed32::ONE / Fixed32::ZERO;
+}
@@ -0,0 +1,15 @@
+use MathError;
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", MathError::ZeroLength), "length is too close to zero");
+    assert_eq!(format!("{}", MathError::Singular), "value is not invertible");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std_error() {
+    let error: Box<dyn (::std::error::Error)> = Box::new(MathError::NonFinite);
+
+    assert_eq!(error.to_string(), "value is not finite");
+}
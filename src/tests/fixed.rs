@@ -0,0 +1,173 @@
+use consts::PI;
+use { Clamp, Fixed32, Fixed64 };
+
+fn close32(value: Fixed32, expected: f32, tolerance: f32) -> bool {
+    (value.to_f32() - expected).abs() <= tolerance
+}
+
+fn close64(value: Fixed64, expected: f64, tolerance: f64) -> bool {
+    (value.to_f64() - expected).abs() <= tolerance
+}
+
+#[test]
+fn constants() {
+    assert_eq!(Fixed32::ONE.to_bits(), 1 << 16);
+    assert_eq!(Fixed64::ONE.to_bits(), 1 << 32);
+    assert_eq!(Fixed32::PI.to_bits(), 205887);
+    assert_eq!(Fixed64::PI.to_bits(), 13493037705);
+    assert_eq!(Fixed32::HALF_PI.to_bits(), 102944);
+    assert_eq!(Fixed64::TWO_PI.to_bits(), 26986075409);
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Fixed32::from_f32(1.5).to_bits(), 0x18000);
+    assert_eq!(Fixed32::from_f32(-1.5).to_f32(), -1.5);
+    assert_eq!(Fixed32::from_int(-3).to_int(), -3);
+    assert_eq!(Fixed32::from_f32(-1.25).to_int(), -2);
+    assert_eq!(Fixed32::from_f32(1.0e9), Fixed32::MAX);
+    assert_eq!(Fixed32::from_f32(f32::NAN), Fixed32::ZERO);
+    assert_eq!(Fixed32::from_int(40000), Fixed32::MAX);
+    assert_eq!(Fixed32::from(7i16).to_int(), 7);
+    assert_eq!(Fixed64::from(Fixed32::from_f32(-2.75)).to_f64(), -2.75);
+    assert_eq!(Fixed64::from(-100000i32).to_int(), -100000);
+}
+
+#[test]
+fn arithmetic() {
+    let a = Fixed32::from_f32(2.5);
+    let b = Fixed32::from_f32(-1.25);
+
+    assert_eq!((a + b).to_f32(), 1.25);
+    assert_eq!((a - b).to_f32(), 3.75);
+    assert_eq!((a * b).to_f32(), -3.125);
+    assert_eq!((a / b).to_f32(), -2.0);
+    assert_eq!((-a).to_f32(), -2.5);
+
+    let mut c = a;
+    c *= b;
+    c += a;
+    assert_eq!(c.to_f32(), -0.625);
+}
+
+#[test]
+fn overflow() {
+    let big = Fixed32::from_int(30000);
+
+    assert_eq!(big.saturating_add(big), Fixed32::MAX);
+    assert_eq!((-big).saturating_sub(big), Fixed32::MIN);
+    assert_eq!(big.saturating_mul(big), Fixed32::MAX);
+    assert_eq!(big.saturating_mul(-big), Fixed32::MIN);
+    assert_eq!(big.wrapping_add(big), Fixed32::from_bits(30000i32.wrapping_mul(2 << 16)));
+    assert_eq!(big + big, big.wrapping_add(big));
+    assert_eq!(Fixed32::MIN.saturating_neg(), Fixed32::MAX);
+    assert_eq!(big.checked_div(Fixed32::ZERO), None);
+    assert_eq!(big.checked_div(Fixed32::from_f32(0.25)), None);
+    assert_eq!(big.checked_div(Fixed32::from_int(2)), Some(Fixed32::from_int(15000)));
+}
+
+#[test]
+fn rounding() {
+    let v = Fixed32::from_f32(-1.25);
+
+    assert_eq!(v.floor().to_f32(), -2.0);
+    assert_eq!(v.ceil().to_f32(), -1.0);
+    assert_eq!(v.round().to_f32(), -1.0);
+    assert_eq!(Fixed32::from_f32(-2.5).round().to_f32(), -3.0);
+    assert_eq!(Fixed32::from_f32(2.5).round().to_f32(), 3.0);
+    assert_eq!(v.fract().to_f32(), 0.75);
+    assert_eq!(v.abs().to_f32(), 1.25);
+    assert_eq!(v.signum(), -Fixed32::ONE);
+}
+
+#[test]
+fn clamp_and_lerp() {
+    let a = Fixed32::from_int(2);
+    let b = Fixed32::from_int(6);
+
+    assert_eq!(Clamp::clamp(Fixed32::from_int(9), a, b), b);
+    assert_eq!(Fixed32::lerp(a, b, Fixed32::HALF), Fixed32::from_int(4));
+    assert_eq!(Fixed32::lerp(a, b, Fixed32::from_int(2)), b);
+    assert_eq!(Fixed32::lerp_unclamped(a, b, Fixed32::from_int(2)), Fixed32::from_int(10));
+}
+
+#[test]
+fn sqrt() {
+    assert_eq!(Fixed32::from_int(4).sqrt(), Fixed32::from_int(2));
+    assert_eq!(Fixed64::from_int(1 << 20).sqrt(), Fixed64::from_int(1 << 10));
+    assert!(close32(Fixed32::from_int(2).sqrt(), 2.0f32.sqrt(), 2.0e-5));
+    assert!(close64(Fixed64::from_int(2).sqrt(), 2.0f64.sqrt(), 1.0e-9));
+    assert_eq!(Fixed32::from_int(-4).sqrt(), Fixed32::ZERO);
+}
+
+#[test]
+fn trig() {
+    let mut angle = -10.0f32;
+    while angle < 10.0 {
+        let fixed = Fixed32::from_f32(angle);
+        let exact = fixed.to_f64();
+        assert!(close32(fixed.sin(), exact.sin() as f32, 1.0e-4), "sin({})", angle);
+        assert!(close32(fixed.cos(), exact.cos() as f32, 1.0e-4), "cos({})", angle);
+
+        let fixed = Fixed64::from_f32(angle);
+        let exact = fixed.to_f64();
+        assert!(close64(fixed.sin(), exact.sin(), 1.0e-9), "sin({})", angle);
+        assert!(close64(fixed.cos(), exact.cos(), 1.0e-9), "cos({})", angle);
+
+        angle += 0.37;
+    }
+
+    assert_eq!(Fixed32::ZERO.sin(), Fixed32::ZERO);
+    assert_eq!(Fixed32::ZERO.cos(), Fixed32::ONE);
+    assert!(close32(Fixed32::from_f32(PI / 4.0).tan(), 1.0, 1.0e-4));
+}
+
+#[test]
+fn inverse_trig() {
+    let points = [(1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0), (1.0, -1.0), (0.0, 1.0), (-3.0, 0.5), (0.25, -7.0)];
+    for &(y, x) in points.iter() {
+        let expected = (y as f64).atan2(x as f64);
+
+        assert!(close32(Fixed32::atan2(Fixed32::from_f32(y), Fixed32::from_f32(x)), expected as f32, 1.0e-4));
+        assert!(close64(Fixed64::atan2(Fixed64::from_f32(y), Fixed64::from_f32(x)), expected, 1.0e-9));
+    }
+
+    assert_eq!(Fixed32::atan2(Fixed32::ZERO, Fixed32::ZERO), Fixed32::ZERO);
+    assert!(close64(Fixed64::from_f32(0.5).asin(), 0.5f64.asin(), 1.0e-9));
+    assert!(close64(Fixed64::from_f32(-0.5).acos(), (-0.5f64).acos(), 1.0e-9));
+    assert!(close32(Fixed32::from_int(2).acos(), 0.0, 1.0e-4));
+}
+
+#[test]
+fn exp_and_ln() {
+    let mut x = -8.0f32;
+    while x < 8.0 {
+        let fixed = Fixed32::from_f32(x);
+        let exact = fixed.to_f64().exp();
+        assert!(close32(fixed.exp(), exact as f32, 1.0e-4 * exact.max(1.0) as f32), "exp({})", x);
+
+        let fixed = Fixed64::from_f32(x);
+        let exact = fixed.to_f64().exp();
+        assert!(close64(fixed.exp(), exact, 1.0e-9 * exact.max(1.0)), "exp({})", x);
+
+        x += 0.37;
+    }
+
+    let mut x = 0.001f32;
+    while x < 20000.0 {
+        let exact = Fixed64::from_f32(x).to_f64().ln();
+        assert!(close64(Fixed64::from_f32(x).ln(), exact, 1.0e-9), "ln({})", x);
+        assert!(close32(Fixed32::from_f32(x).ln(), Fixed32::from_f32(x).to_f64().ln() as f32, 1.0e-4), "ln({})", x);
+
+        x *= 1.7;
+    }
+
+    assert_eq!(Fixed64::ZERO.exp(), Fixed64::ONE);
+    assert_eq!(Fixed64::ONE.ln(), Fixed64::ZERO);
+    assert_eq!(Fixed64::from_int(30).exp(), Fixed64::MAX);
+    assert_eq!(Fixed32::from_int(-30).exp(), Fixed32::ZERO);
+    assert_eq!(Fixed64::ZERO.ln(), Fixed64::MIN);
+    assert_eq!(Fixed32::from_int(-1).ln(), Fixed32::MIN);
+    assert!(close64(Fixed64::MAX.ln(), Fixed64::MAX.to_f64().ln(), 1.0e-9));
+    assert!(close64(Fixed64::EPSILON.ln(), Fixed64::EPSILON.to_f64().ln(), 1.0e-9));
+}
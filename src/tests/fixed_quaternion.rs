@@ -0,0 +1,262 @@
+use consts::PI;
+use { Fixed64, FixedQuaternion, FixedVector3, Quaternion, Vector3 };
+
+fn angle(radians: f32) -> Fixed64 {
+    Fixed64::from_f32(radians)
+}
+
+fn nearly(value: Fixed64, expected: Fixed64) -> bool {
+    (value - expected).abs().to_bits() <= 4
+}
+
+fn close(value: FixedVector3, expected: Vector3) -> bool {
+    Vector3::distance(value.into(), expected) < 1e-5
+}
+
+#[test]
+fn conversions() {
+    let q = Quaternion::from_angle_axis(1.2, Vector3::UP);
+    let fixed = FixedQuaternion::from(q);
+
+    assert!(Quaternion::angle_between(fixed.into(), q) < 1e-3);
+    assert_eq!(FixedQuaternion::from(Quaternion::IDENTITY), FixedQuaternion::IDENTITY);
+}
+
+#[test]
+fn from_angle_axis() {
+    let fixed = FixedQuaternion::from_angle_axis(angle(PI / 2.0), FixedVector3::UP);
+    let float = Quaternion::from_angle_axis(PI / 2.0, Vector3::UP);
+
+    assert!(Quaternion::angle_between(fixed.into(), float) < 1e-3);
+}
+
+#[test]
+fn from_euler() {
+    let euler = Vector3::new(0.3, -1.1, 0.7);
+    let fixed = FixedQuaternion::from_euler(euler.into());
+
+    assert!(Quaternion::angle_between(fixed.into(), Quaternion::from_euler(euler)) < 1e-3);
+}
+
+#[test]
+fn from_euler_combined_axes() {
+    let fixed = FixedQuaternion::from_euler_components(angle(0.3), angle(-0.5), angle(0.7));
+    let expected = Quaternion::from_angle_axis(-0.5, Vector3::UP)
+        * Quaternion::from_angle_axis(0.3, Vector3::FORWARD)
+        * Quaternion::from_angle_axis(0.7, Vector3::RIGHT);
+
+    assert!(Quaternion::angle_between(fixed.into(), expected) < 1e-3);
+    assert!((fixed.magnitude() - Fixed64::ONE).abs().to_f32() < 1e-6);
+}
+
+#[test]
+fn rotate_vector() {
+    let q = FixedQuaternion::from_angle_axis(angle(PI / 2.0), FixedVector3::UP);
+
+    assert!(close(q.forward(), Vector3::RIGHT));
+    assert!(close(q * FixedVector3::RIGHT, -Vector3::FORWARD));
+    assert!(close(q.up(), Vector3::UP));
+}
+
+#[test]
+fn multiply() {
+    let a = FixedQuaternion::from_angle_axis(angle(PI / 4.0), FixedVector3::UP);
+    let b = FixedQuaternion::from_angle_axis(angle(PI / 2.0), FixedVector3::UP);
+
+    assert!(Quaternion::angle_between((a * a).into(), b.into()) < 1e-3);
+    assert!(Quaternion::angle_between((a * a.inverse()).into(), Quaternion::IDENTITY) < 1e-3);
+    assert_eq!(a.conjugate().conjugate(), a);
+}
+
+#[test]
+fn to_angle_axis() {
+    let q = FixedQuaternion::from_angle_axis(angle(1.0), FixedVector3::RIGHT);
+    let mut out_angle = Fixed64::ZERO;
+    let mut out_axis = FixedVector3::ZERO;
+    q.to_angle_axis(&mut out_angle, &mut out_axis);
+
+    assert!((out_angle.to_f32() - 1.0).abs() < 1e-5);
+    assert!(close(out_axis, Vector3::RIGHT));
+}
+
+#[test]
+fn slerp() {
+    let a = FixedQuaternion::IDENTITY;
+    let b = FixedQuaternion::from_angle_axis(angle(PI / 2.0), FixedVector3::UP);
+    let half = FixedQuaternion::from_angle_axis(angle(PI / 4.0), FixedVector3::UP);
+
+    assert!(Quaternion::angle_between(FixedQuaternion::slerp(a, b, Fixed64::HALF).into(), half.into()) < 1e-3);
+    assert!(Quaternion::angle_between(FixedQuaternion::slerp(a, -b, Fixed64::HALF).into(), half.into()) < 1e-3);
+    assert!(nearly(FixedQuaternion::slerp(a, b, Fixed64::ZERO).w, Fixed64::ONE));
+}
+
+#[test]
+fn angle_between() {
+    let a = FixedQuaternion::IDENTITY;
+    let b = FixedQuaternion::from_angle_axis(angle(PI / 2.0), FixedVector3::UP);
+
+    assert!((FixedQuaternion::angle_between(a, b).to_f32() - PI / 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn normalized() {
+    let q = FixedQuaternion::new(Fixed64::ZERO, Fixed64::from_int(3), Fixed64::ZERO, Fixed64::from_int(4));
+
+    assert_eq!(q.magnitude(), Fixed64::from_int(5));
+    assert!(nearly(q.normalized().magnitude(), Fixed64::ONE));
+    assert_eq!(FixedQuaternion::default().normalized(), FixedQuaternion::IDENTITY);
+}
+
+#[test]
+fn inverse() {
+    let q = FixedQuaternion::from_angle_axis(angle(0.8), FixedVector3::RIGHT) * Fixed64::from_int(3);
+    let float = Quaternion::from(q).inverse();
+    let inverse = q.inverse();
+
+    assert!((inverse.x.to_f32() - float.x).abs() < 1e-6);
+    assert!((inverse.w.to_f32() - float.w).abs() < 1e-6);
+    assert_eq!(q.checked_inverse(), Some(inverse));
+
+    // Components this small square to zero, scaling keeps the inverse exact
+    let tiny = FixedQuaternion::new(Fixed64::ZERO, Fixed64::ZERO, Fixed64::ZERO, Fixed64::from_f32(1e-5));
+    assert!((tiny.inverse().w.to_f32() - 1e5).abs() < 1.0);
+
+    assert_eq!(FixedQuaternion::default().checked_inverse(), None);
+    assert_eq!(FixedQuaternion::new(Fixed64::ZERO, Fixed64::ZERO, Fixed64::ZERO, Fixed64::EPSILON).checked_inverse(), None);
+}
+
+// Squaring 50000 overflows Q32.32, magnitude and normalized have to scale first
+#[test]
+fn large_components() {
+    let q = FixedQuaternion::new(Fixed64::from_int(50000), Fixed64::ZERO, Fixed64::ZERO, Fixed64::ONE);
+    let n = q.normalized();
+
+    assert!(nearly(n.x, Fixed64::ONE));
+    assert!((n.w.to_f32() - 2e-5).abs() < 1e-6);
+    assert!((q.magnitude().to_f32() - 50000.0).abs() < 1e-2);
+    assert!(Quaternion::angle_between(q.inverse().into(), Quaternion::from(n).inverse()) < 1e-3);
+
+    let max = FixedQuaternion::new(Fixed64::MAX, Fixed64::MAX, Fixed64::ZERO, Fixed64::ZERO);
+    assert_eq!(max.magnitude(), Fixed64::MAX);
+}
+
+fn same_rotation(fixed: FixedQuaternion, float: Quaternion) -> bool {
+    Quaternion::angle_between(fixed.into(), float) < 1e-3
+}
+
+#[test]
+fn look_rotation() {
+    let forward = Vector3::new(1.0, 2.0, -0.5);
+    let up = Vector3::new(0.2, 1.0, 0.1);
+
+    assert!(same_rotation(FixedQuaternion::look_rotation(forward.into(), up.into()), Quaternion::look_rotation(forward, up)));
+    assert!(same_rotation(FixedQuaternion::from_orientation(forward.into(), up.into()), Quaternion::from_orientation(forward, up)));
+    assert!(same_rotation(FixedQuaternion::from_direction(forward.into()), Quaternion::from_direction(forward)));
+    assert!(close(FixedQuaternion::look_rotation(FixedVector3::RIGHT, FixedVector3::UP).forward(), Vector3::RIGHT));
+
+    // Degenerate bases fall back like the float version
+    assert_eq!(FixedQuaternion::look_rotation(FixedVector3::ZERO, FixedVector3::UP), FixedQuaternion::IDENTITY);
+    assert!(close(FixedQuaternion::look_rotation(FixedVector3::UP, FixedVector3::UP).forward(), Vector3::UP));
+}
+
+#[test]
+fn from_to_rotation() {
+    let from = Vector3::new(1.0, 0.5, 0.0);
+    let to = Vector3::new(-0.3, 0.2, 1.0);
+    let q = FixedQuaternion::from_to_rotation(from.into(), to.into());
+
+    assert!(same_rotation(q, Quaternion::from_to_rotation(from, to)));
+    assert!(close(q * FixedVector3::from(from.normalized()), to.normalized()));
+    assert_eq!(FixedQuaternion::from_to_rotation(FixedVector3::UP, FixedVector3::UP), FixedQuaternion::IDENTITY);
+    assert!(close(FixedQuaternion::from_to_rotation(FixedVector3::UP, -FixedVector3::UP) * FixedVector3::UP, -Vector3::UP));
+}
+
+#[test]
+fn to_euler() {
+    let euler = Vector3::new(0.3, -1.1, 0.7);
+    let fixed = FixedQuaternion::from_euler(euler.into()).to_euler();
+
+    assert!(close(fixed, euler));
+    assert!(close(FixedQuaternion::from(Quaternion::from_euler(euler)).to_euler(), Quaternion::from_euler(euler).to_euler()));
+
+    // At the pole the angles are not unique, they still describe the same rotation
+    let pole = FixedQuaternion::from_angle_axis(angle(PI / 2.0), FixedVector3::FORWARD);
+    assert!(same_rotation(FixedQuaternion::from_euler(pole.to_euler()), pole.into()));
+}
+
+#[test]
+fn to_euler_at_the_poles() {
+    // Yaw and roll come back combined in y, matching Quaternion::to_euler
+    for &(euler, expected) in [
+        (Vector3::new(PI / 2.0, 0.7, 0.0), Vector3::new(PI / 2.0, 0.7, 0.0)),
+        (Vector3::new(-PI / 2.0, 0.7, 0.0), Vector3::new(-PI / 2.0, 0.7, 0.0)),
+        (Vector3::new(PI / 2.0, 0.7, 0.2), Vector3::new(PI / 2.0, 0.9, 0.0)),
+        (Vector3::new(-PI / 2.0, 0.7, 0.2), Vector3::new(-PI / 2.0, 0.5, 0.0))
+    ].iter() {
+        let q = FixedQuaternion::from_euler(euler.into());
+
+        assert!(close(q.to_euler(), expected), "{:?} {:?}", q.to_euler(), expected);
+        assert!(same_rotation(FixedQuaternion::from_euler(q.to_euler()), q.into()));
+    }
+}
+
+#[test]
+fn rotate_towards() {
+    let a = FixedQuaternion::IDENTITY;
+    let b = FixedQuaternion::from_angle_axis(angle(1.0), FixedVector3::UP);
+    let step = FixedQuaternion::from_angle_axis(angle(0.25), FixedVector3::UP);
+
+    assert!(same_rotation(FixedQuaternion::rotate_towards(a, b, angle(0.25)), step.into()));
+    assert!(same_rotation(FixedQuaternion::rotate_towards(a, -b, angle(0.25)), step.into()));
+    assert_eq!(FixedQuaternion::rotate_towards(a, b, angle(2.0)), b);
+}
+
+#[test]
+fn exp_and_ln() {
+    let q = Quaternion::new(0.3, -0.2, 0.5, 0.8);
+    let fixed = FixedQuaternion::from(q);
+
+    for &(value, expected) in [(fixed.exp(), q.exp()), (fixed.ln(), q.ln()), (fixed.pow(angle(0.4)), q.pow(0.4))].iter() {
+        let value = Quaternion::from(value);
+        assert!((value.x - expected.x).abs() < 1e-5 && (value.y - expected.y).abs() < 1e-5, "{:?} {:?}", value, expected);
+        assert!((value.z - expected.z).abs() < 1e-5 && (value.w - expected.w).abs() < 1e-5, "{:?} {:?}", value, expected);
+    }
+
+    assert_eq!(FixedQuaternion::IDENTITY.ln(), FixedQuaternion::new(Fixed64::ZERO, Fixed64::ZERO, Fixed64::ZERO, Fixed64::ZERO));
+    assert_eq!(FixedQuaternion::default().exp(), FixedQuaternion::IDENTITY);
+}
+
+#[test]
+fn squad() {
+    let keys = [0.0, 0.6, 1.5, 1.9].iter()
+        .map(|&a| Quaternion::from_angle_axis(a, Vector3::new(0.3, 1.0, 0.2).normalized()))
+        .collect::<Vec<_>>();
+    let fixed = keys.iter().map(|&q| FixedQuaternion::from(q)).collect::<Vec<_>>();
+
+    let tangent = Quaternion::squad_tangent(keys[0], keys[1], keys[2]);
+    let fixed_tangent = FixedQuaternion::squad_tangent(fixed[0], fixed[1], fixed[2]);
+    assert!(same_rotation(fixed_tangent, tangent));
+
+    let next = Quaternion::squad_tangent(keys[1], keys[2], keys[3]);
+    let fixed_next = FixedQuaternion::squad_tangent(fixed[1], fixed[2], fixed[3]);
+    let q = Quaternion::squad(keys[1], keys[2], tangent, next, 0.3);
+    assert!(same_rotation(FixedQuaternion::squad(fixed[1], fixed[2], fixed_tangent, fixed_next, angle(0.3)), q));
+}
+
+#[test]
+fn integrate() {
+    let q = FixedQuaternion::from_angle_axis(angle(0.4), FixedVector3::RIGHT);
+    let velocity = Vector3::new(0.0, 2.0, 0.5);
+    let dt = 0.1;
+
+    let exact = Quaternion::from(q).integrate_exact(velocity, dt);
+    assert!(same_rotation(q.integrate_exact(velocity.into(), angle(dt)), exact));
+    assert!(same_rotation(q.integrate(velocity.into(), angle(dt)), Quaternion::from(q).integrate(velocity, dt)));
+
+    let recovered = FixedQuaternion::angular_velocity(q, exact.into(), angle(dt));
+    assert!(Vector3::distance(recovered.into(), velocity) < 1e-3);
+
+    assert_eq!(FixedQuaternion::angular_velocity(q, exact.into(), Fixed64::ZERO), FixedVector3::ZERO);
+    let turn = FixedQuaternion::from_angle_axis(Fixed64::ONE, FixedVector3::UP);
+    assert_eq!(FixedQuaternion::angular_velocity(FixedQuaternion::IDENTITY, turn, Fixed64::EPSILON).y, Fixed64::MAX);
+}
@@ -0,0 +1,145 @@
+use { Fixed64, FixedVector3, Vector3 };
+
+fn fixed(x: f32, y: f32, z: f32) -> FixedVector3 {
+    FixedVector3::from(Vector3::new(x, y, z))
+}
+
+fn close(value: Fixed64, expected: f32) -> bool {
+    (value.to_f32() - expected).abs() < 1e-6
+}
+
+// Within a few units in the last place
+fn nearly(value: Fixed64, expected: Fixed64) -> bool {
+    (value - expected).abs().to_bits() <= 4
+}
+
+#[test]
+fn conversions() {
+    let v = Vector3::new(1.5, -2.25, 0.125);
+
+    assert_eq!(Vector3::from(FixedVector3::from(v)), v);
+    assert_eq!(FixedVector3::from(Vector3::ONE), FixedVector3::ONE);
+}
+
+#[test]
+fn magnitude() {
+    let v = fixed(3.0, 4.0, 12.0);
+
+    assert_eq!(v.sqr_magnitude(), Fixed64::from_int(169));
+    assert!(nearly(v.magnitude(), Fixed64::from_int(13)));
+    assert_eq!(FixedVector3::ZERO.magnitude(), Fixed64::ZERO);
+}
+
+#[test]
+fn magnitude_of_large_vectors() {
+    // The squared length is far outside the Q32.32 range
+    let v = fixed(3.0e6, 4.0e6, 0.0);
+
+    assert!(nearly(v.magnitude(), Fixed64::from_int(5000000)));
+    assert!(close(v.normalized().x, 0.6));
+
+    // The length itself does not fit, it saturates instead of wrapping negative
+    let v = fixed(2.0e9, 2.0e9, 0.0);
+    assert_eq!(v.magnitude(), Fixed64::MAX);
+    assert_eq!(FixedVector3::distance(v, FixedVector3::ZERO), Fixed64::MAX);
+    assert!(nearly(v.clamp_magnitude(Fixed64::ONE).magnitude(), Fixed64::ONE));
+}
+
+#[test]
+fn normalize() {
+    let mut v = fixed(0.0, 0.0, -5.0);
+    v.normalize();
+
+    assert_eq!(v, -FixedVector3::FORWARD);
+    assert_eq!(FixedVector3::ZERO.normalized(), FixedVector3::ZERO);
+    assert!(close(fixed(1.0, 2.0, 3.0).normalized().magnitude(), 1.0));
+}
+
+#[test]
+fn dot_and_cross() {
+    assert_eq!(FixedVector3::dot(fixed(1.0, 2.0, 3.0), fixed(4.0, -5.0, 6.0)), Fixed64::from_int(12));
+    assert_eq!(FixedVector3::cross(FixedVector3::RIGHT, FixedVector3::UP), FixedVector3::FORWARD);
+}
+
+#[test]
+fn angle() {
+    let angle = FixedVector3::angle(FixedVector3::RIGHT, fixed(1.0, 1.0, 0.0));
+
+    assert!(close(angle, Vector3::angle(Vector3::RIGHT, Vector3::new(1.0, 1.0, 0.0))));
+}
+
+#[test]
+fn lerp_and_move_towards() {
+    let a = FixedVector3::ZERO;
+    let b = fixed(2.0, 4.0, 6.0);
+
+    assert_eq!(FixedVector3::lerp(a, b, Fixed64::HALF), fixed(1.0, 2.0, 3.0));
+    assert_eq!(FixedVector3::lerp(a, b, Fixed64::from_int(2)), b);
+    assert_eq!(FixedVector3::move_towards(a, fixed(0.0, 10.0, 0.0), Fixed64::from_int(3)), fixed(0.0, 3.0, 0.0));
+}
+
+#[test]
+fn project_and_reflect() {
+    let v = fixed(1.0, 1.0, 0.0);
+
+    assert_eq!(FixedVector3::project(v, FixedVector3::UP), FixedVector3::UP);
+    assert_eq!(FixedVector3::project_on_plane(v, FixedVector3::UP), FixedVector3::RIGHT);
+    assert_eq!(FixedVector3::reflect(fixed(1.0, -1.0, 0.0), FixedVector3::UP), v);
+}
+
+#[test]
+fn ops() {
+    let a = fixed(1.0, 2.0, 3.0);
+    let two = Fixed64::from_int(2);
+
+    assert_eq!(a + a, a * two);
+    assert_eq!(two * a, fixed(2.0, 4.0, 6.0));
+    assert_eq!(a * two / two, a);
+    assert_eq!(a - a, FixedVector3::ZERO);
+
+    let mut b = a;
+    b += a;
+    b *= two;
+    assert_eq!(b, fixed(4.0, 8.0, 12.0));
+}
+
+#[test]
+fn component_division() {
+    assert_eq!(fixed(3.0, -4.0, 1.0) / fixed(2.0, 2.0, 4.0), fixed(1.5, -2.0, 0.25));
+}
+
+#[test]
+fn index_and_arrays() {
+    let mut v = fixed(1.0, 2.0, 3.0);
+    assert_eq!(v[2], Fixed64::from_int(3));
+
+    v[0] = Fixed64::from_int(5);
+    assert_eq!(v.x, Fixed64::from_int(5));
+
+    let array: [Fixed64; 3] = v.into();
+    assert_eq!(FixedVector3::from(array), v);
+    assert_eq!(FixedVector3::from_slice(&array), v);
+    assert_eq!(FixedVector3::from((Fixed64::ONE, Fixed64::ZERO, Fixed64::ZERO)), FixedVector3::RIGHT);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_range() {
+    let _ = FixedVector3::ZERO[3];
+}
+
+#[test]
+fn rotate_towards() {
+    let current = fixed(2.0, 0.0, 0.0);
+    let target = fixed(0.0, 0.0, 4.0);
+    let float = Vector3::rotate_towards(current.into(), target.into(), 0.5, 1.0);
+    let v = FixedVector3::rotate_towards(current, target, Fixed64::HALF, Fixed64::ONE);
+
+    assert!(Vector3::distance(v.into(), float) < 1e-5);
+    assert!(close(v.magnitude(), 3.0));
+    assert_eq!(FixedVector3::rotate_towards(current, target, Fixed64::from_int(2), Fixed64::from_int(3)), target);
+
+    // Opposite directions still rotate by the requested angle
+    let opposite = FixedVector3::rotate_towards(FixedVector3::RIGHT, -FixedVector3::RIGHT, Fixed64::HALF, Fixed64::ZERO);
+    assert!((FixedVector3::angle(opposite, FixedVector3::RIGHT).to_f32() - 0.5).abs() < 1e-5);
+}
@@ -0,0 +1,28 @@
+use cgmath;
+
+use { Vector3, Quaternion, Matrix4x4 };
+use super::sequential_matrix;
+
+#[test]
+fn vectors() {
+    let v: cgmath::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+    assert_eq!(v, cgmath::Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector3::from(v), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn quaternion() {
+    let q: cgmath::Quaternion<f32> = Quaternion::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!((q.v.x, q.v.y, q.v.z, q.s), (1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Quaternion::from(q).w, 4.0);
+}
+
+#[test]
+fn matrix4x4_layout() {
+    let m = sequential_matrix();
+    let c: cgmath::Matrix4<f32> = m.into();
+
+    assert_eq!(c.x, cgmath::Vector4::new(0.0, 4.0, 8.0, 12.0));
+    assert_eq!(c.w, cgmath::Vector4::new(3.0, 7.0, 11.0, 15.0));
+    assert_eq!(Matrix4x4::from(c), m);
+}
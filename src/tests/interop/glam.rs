@@ -0,0 +1,41 @@
+use glam;
+
+use { Vector3, Vector4, Quaternion, Matrix4x4 };
+use super::sequential_matrix;
+
+#[test]
+fn vectors() {
+    let v: glam::Vec3 = Vector3::new(1.0, 2.0, 3.0).into();
+    assert_eq!(v, glam::Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector3::from(v), Vector3::new(1.0, 2.0, 3.0));
+
+    let v: glam::Vec4 = Vector4::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!(v.to_array(), [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(Vector4::from(v), Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn quaternion() {
+    let q: glam::Quat = Quaternion::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!(q.to_array(), [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(Quaternion::from(q).w, 4.0);
+}
+
+#[test]
+fn matrix4x4_layout() {
+    let m = sequential_matrix();
+    let g: glam::Mat4 = m.into();
+
+    assert_eq!(g.col(0).to_array(), [0.0, 4.0, 8.0, 12.0]);
+    assert_eq!(g.row(0).to_array(), [0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(Matrix4x4::from(g), m);
+}
+
+#[test]
+fn matrix4x4_transforms_agree() {
+    let m = Matrix4x4::make_from_translation(Vector3::new(1.0, 2.0, 3.0));
+    let g: glam::Mat4 = m.into();
+
+    let p = g.transform_point3(glam::Vec3::new(1.0, 1.0, 1.0));
+    assert_eq!(Vector3::from(p), m.transform_point(Vector3::ONE));
+}
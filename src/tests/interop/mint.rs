@@ -0,0 +1,54 @@
+use mint;
+
+use { Vector3, Vector4, Quaternion, Matrix3x3, Matrix4x4 };
+use super::sequential_matrix;
+
+#[test]
+fn vectors() {
+    let v: mint::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+    assert_eq!((v.x, v.y, v.z), (1.0, 2.0, 3.0));
+    assert_eq!(Vector3::from(v), Vector3::new(1.0, 2.0, 3.0));
+
+    let v: mint::Vector4<f32> = Vector4::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!(v.w, 4.0);
+    assert_eq!(Vector4::from(v), Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn quaternion() {
+    let q: mint::Quaternion<f32> = Quaternion::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!((q.v.x, q.v.y, q.v.z, q.s), (1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Quaternion::from(q).w, 4.0);
+}
+
+#[test]
+fn matrix4x4_column_layout() {
+    let m = sequential_matrix();
+    let c: mint::ColumnMatrix4<f32> = m.into();
+
+    assert_eq!((c.x.x, c.x.y, c.x.z, c.x.w), (0.0, 4.0, 8.0, 12.0));
+    assert_eq!((c.w.x, c.w.y, c.w.z, c.w.w), (3.0, 7.0, 11.0, 15.0));
+    assert_eq!(Matrix4x4::from(c), m);
+}
+
+#[test]
+fn matrix4x4_row_layout() {
+    let m = sequential_matrix();
+    let r: mint::RowMatrix4<f32> = m.into();
+
+    assert_eq!((r.x.x, r.x.y, r.x.z, r.x.w), (0.0, 1.0, 2.0, 3.0));
+    assert_eq!((r.w.x, r.w.y, r.w.z, r.w.w), (12.0, 13.0, 14.0, 15.0));
+    assert_eq!(Matrix4x4::from(r), m);
+}
+
+#[test]
+fn matrix3x3_layout() {
+    let m = Matrix3x3::new(Vector3::new(0.0, 3.0, 6.0), Vector3::new(1.0, 4.0, 7.0), Vector3::new(2.0, 5.0, 8.0));
+    let c: mint::ColumnMatrix3<f32> = m.into();
+    let r: mint::RowMatrix3<f32> = m.into();
+
+    assert_eq!((c.y.x, c.y.y, c.y.z), (1.0, 4.0, 7.0));
+    assert_eq!((r.y.x, r.y.y, r.y.z), (3.0, 4.0, 5.0));
+    assert_eq!(Matrix3x3::from(c), m);
+    assert_eq!(Matrix3x3::from(r), m);
+}
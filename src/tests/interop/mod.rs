@@ -0,0 +1,20 @@
+use Matrix4x4;
+
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "cgmath")]
+mod cgmath;
+
+// Every element is distinct, m[(row, column)] == row * 4 + column, with the translation in the last column
+fn sequential_matrix() -> Matrix4x4 {
+    Matrix4x4::from([
+        0.0, 1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0, 7.0,
+        8.0, 9.0, 10.0, 11.0,
+        12.0, 13.0, 14.0, 15.0
+    ])
+}
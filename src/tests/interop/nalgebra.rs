@@ -0,0 +1,32 @@
+use nalgebra;
+
+use { Vector3, Quaternion, Matrix4x4 };
+use super::sequential_matrix;
+
+#[test]
+fn vectors() {
+    let v: nalgebra::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+    assert_eq!(v, nalgebra::Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector3::from(v), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn quaternion() {
+    let q: nalgebra::Quaternion<f32> = Quaternion::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!((q.i, q.j, q.k, q.w), (1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Quaternion::from(q).w, 4.0);
+
+    let u: nalgebra::UnitQuaternion<f32> = Quaternion::IDENTITY.into();
+    assert_eq!(Quaternion::from(u), Quaternion::IDENTITY);
+}
+
+#[test]
+fn matrix4x4_layout() {
+    let m = sequential_matrix();
+    let n: nalgebra::Matrix4<f32> = m.into();
+
+    assert_eq!(n[(0, 3)], 3.0);
+    assert_eq!(n[(3, 0)], 12.0);
+    assert_eq!(n.as_slice()[..4], [0.0, 4.0, 8.0, 12.0]);
+    assert_eq!(Matrix4x4::from(n), m);
+}
@@ -0,0 +1,76 @@
+use { Mask4, Mask8 };
+use core::f32;
+
+#[test]
+fn comparisons() {
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let b = [4.0, 2.0, 2.0, 1.0];
+
+    assert_eq!(Mask4::lt(a, b), Mask4::new([true, false, false, false]));
+    assert_eq!(Mask4::le(a, b), Mask4::new([true, true, false, false]));
+    assert_eq!(Mask4::gt(a, b), Mask4::new([false, false, true, true]));
+    assert_eq!(Mask4::ge(a, b), Mask4::new([false, true, true, true]));
+}
+
+#[test]
+fn nan_lanes_compare_false() {
+    let a = [f32::NAN; 4];
+
+    assert!(Mask4::lt(a, [0.0; 4]).none());
+    assert!(Mask4::ge(a, [0.0; 4]).none());
+}
+
+#[test]
+fn reductions() {
+    let mask = Mask8::new([true, false, false, true, false, false, false, true]);
+
+    assert!(mask.any());
+    assert!(!mask.all());
+    assert!(!mask.none());
+    assert!(Mask8::ALL.all());
+    assert!(Mask8::NONE.none());
+    assert_eq!(mask.bitmask(), 0b1000_1001);
+    assert!(mask.test(3));
+    assert!(!mask.test(4));
+}
+
+#[test]
+fn logic_ops() {
+    let a = Mask4::new([true, true, false, false]);
+    let b = Mask4::new([true, false, true, false]);
+
+    assert_eq!(a & b, Mask4::new([true, false, false, false]));
+    assert_eq!(a | b, Mask4::new([true, true, true, false]));
+    assert_eq!(a ^ b, Mask4::new([false, true, true, false]));
+    assert_eq!(!a, Mask4::new([false, false, true, true]));
+}
+
+#[test]
+fn select() {
+    let mask = Mask4::new([true, false, true, false]);
+
+    assert_eq!(Mask4::select(mask, [1.0; 4], [2.0; 4]), [1.0, 2.0, 1.0, 2.0]);
+}
+
+// The blend copies bits, so NaN payloads and signed zeros come through unchanged
+#[test]
+fn select_is_bitwise() {
+    let mask = Mask4::new([true, false, true, false]);
+    let out = Mask4::select(mask, [-0.0, 1.0, f32::NAN, 1.0], [2.0, 0.0, 2.0, -0.0]);
+
+    assert_eq!(out[0].to_bits(), (-0.0f32).to_bits());
+    assert!(out[2].is_nan());
+    assert_eq!(out[3].to_bits(), (-0.0f32).to_bits());
+}
+
+#[test]
+fn conversions() {
+    let lanes = [false, true, true, false];
+    let mut mask = Mask4::from(lanes);
+
+    assert_eq!(<[bool; 4]>::from(mask), lanes);
+    assert_eq!(Mask4::splat(true), Mask4::ALL);
+
+    mask.set(0, true);
+    assert_eq!(mask.to_array(), [true, true, true, false]);
+}
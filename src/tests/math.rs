@@ -0,0 +1,32 @@
+use consts::PI;
+use math;
+
+#[test]
+fn roots_and_trig() {
+    assert_eq!(math::sqrt(4.0), 2.0);
+    assert_eq!(math::sin(0.0), 0.0);
+    assert_eq!(math::cos(0.0), 1.0);
+    assert!((math::acos(-1.0) - PI).abs() < 1e-6);
+    assert!((math::atan2(1.0, 1.0) - PI / 4.0).abs() < 1e-6);
+    assert!((math::tan(PI / 4.0) - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn exp_and_ln() {
+    assert_eq!(math::exp(0.0), 1.0);
+    assert_eq!(math::ln(1.0), 0.0);
+    assert!((math::ln(math::exp(2.0)) - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn rounding() {
+    assert_eq!(math::floor(-1.5), -2.0);
+    assert_eq!(math::ceil(-1.5), -1.0);
+    assert_eq!(math::round(2.5), 3.0);
+    assert_eq!(math::round(-2.5), -3.0);
+}
+
+#[test]
+fn mul_add() {
+    assert_eq!(math::mul_add(2.0, 3.0, 4.0), 10.0);
+}
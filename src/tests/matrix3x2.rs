@@ -0,0 +1,72 @@
+use consts::{ DEG2RAD };
+use { Matrix3x2, Vector2, Vector3, ApproxEq };
+
+#[test]
+fn constants() {
+    assert_eq!(Matrix3x2::IDENTITY * Matrix3x2::IDENTITY, Matrix3x2::IDENTITY);
+    assert_eq!(Matrix3x2::IDENTITY.transform_point(Vector2::ONE), Vector2::ONE);
+}
+
+#[test]
+fn make_from_translation() {
+    let m = Matrix3x2::make_from_translation(Vector2::new(1.0, 2.0));
+
+    assert_eq!(m.transform_point(Vector2::ZERO), Vector2::new(1.0, 2.0));
+    assert_eq!(m.transform_vector(Vector2::RIGHT), Vector2::RIGHT);
+    assert_eq!(m.translation(), Vector2::new(1.0, 2.0));
+}
+
+#[test]
+fn make_from_rotation() {
+    let m = Matrix3x2::make_from_rotation(90.0 * DEG2RAD);
+
+    assert_approx_eq!(m.transform_vector(Vector2::RIGHT), Vector2::UP);
+}
+
+#[test]
+fn make_from_scale() {
+    let m = Matrix3x2::make_from_scale(Vector2::new(2.0, 3.0));
+
+    assert_eq!(m * Vector2::ONE, Vector2::new(2.0, 3.0));
+}
+
+#[test]
+fn make_from_skew() {
+    let m = Matrix3x2::make_from_skew(45.0 * DEG2RAD, 0.0);
+
+    assert_approx_eq!(m.transform_point(Vector2::new(0.0, 1.0)), Vector2::new(1.0, 1.0));
+    assert_approx_eq!(m.transform_point(Vector2::new(1.0, 0.0)), Vector2::new(1.0, 0.0));
+}
+
+#[test]
+fn make_from_trs() {
+    let m = Matrix3x2::make_from_trs(Vector2::new(1.0, 2.0), 90.0 * DEG2RAD, Vector2::new(2.0, 2.0));
+    let expected = Matrix3x2::make_from_translation(Vector2::new(1.0, 2.0)) *
+        Matrix3x2::make_from_rotation(90.0 * DEG2RAD) *
+        Matrix3x2::make_from_scale(Vector2::new(2.0, 2.0));
+
+    assert_approx_eq!(m, expected);
+    assert_approx_eq!(m.transform_point(Vector2::RIGHT), Vector2::new(1.0, 4.0));
+}
+
+#[test]
+fn inverse() {
+    let m = Matrix3x2::make_from_trs(Vector2::new(1.0, 2.0), 30.0 * DEG2RAD, Vector2::new(2.0, 0.5)) *
+        Matrix3x2::make_from_skew(10.0 * DEG2RAD, 0.0);
+    let point = Vector2::new(3.0, -2.0);
+
+    assert!(Vector2::distance(m.inverse().transform_point(m.transform_point(point)), point) < 1e-5);
+    assert_approx_eq!(m.determinant() * m.inverse().determinant(), 1.0);
+}
+
+#[test]
+fn to_matrix4x4() {
+    let m = Matrix3x2::make_from_trs(Vector2::new(1.0, 2.0), 90.0 * DEG2RAD, Vector2::new(2.0, 3.0));
+    let point = m.transform_point(Vector2::new(0.5, -1.0));
+
+    let m4 = m.to_matrix4x4();
+    let point4 = m4.transform_point(Vector3::new(0.5, -1.0, 7.0));
+
+    assert_approx_eq!(Vector2::new(point4.x, point4.y), point);
+    assert_approx_eq!(point4.z, 7.0);
+}
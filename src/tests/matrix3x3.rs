@@ -0,0 +1,39 @@
+use consts::{ DEG2RAD };
+use { Matrix3x3, Vector3, Quaternion, ApproxEq };
+
+#[test]
+fn constants() {
+    assert_eq!(Matrix3x3::IDENTITY * Matrix3x3::IDENTITY, Matrix3x3::IDENTITY);
+    assert_eq!(Matrix3x3::IDENTITY * Matrix3x3::ZERO, Matrix3x3::ZERO);
+}
+
+#[test]
+fn new() {
+    let m = Matrix3x3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0), Vector3::new(7.0, 8.0, 9.0));
+
+    assert_eq!(m.column(1), Vector3::new(4.0, 5.0, 6.0));
+    assert_eq!(m.row(1), Vector3::new(2.0, 5.0, 8.0));
+    assert_eq!(m.transpose().column(1), Vector3::new(2.0, 5.0, 8.0));
+}
+
+#[test]
+fn make_from_rotation() {
+    let q = Quaternion::from_angle_axis(90.0 * DEG2RAD, Vector3::UP);
+    let m = Matrix3x3::make_from_rotation(q);
+
+    assert_approx_eq!(m * Vector3::FORWARD, q * Vector3::FORWARD);
+    assert_approx_eq!(m.determinant(), 1.0);
+}
+
+#[test]
+fn determinant() {
+    assert_approx_eq!(Matrix3x3::make_from_scale(Vector3::new(1.0, 2.0, 3.0)).determinant(), 6.0);
+}
+
+#[test]
+fn inverse() {
+    let m = Matrix3x3::new(Vector3::new(2.0, 0.0, 1.0), Vector3::new(1.0, 3.0, 0.0), Vector3::new(0.0, 1.0, 4.0));
+
+    assert_eq!(m * m.inverse(), Matrix3x3::IDENTITY);
+    assert_eq!(m.inverse() * m, Matrix3x3::IDENTITY);
+}
@@ -1 +1,159 @@
-use Matrix4x4;
\ No newline at end of file
+use consts::{ DEG2RAD };
+use { MathError, Matrix3x3, Matrix4x4, Vector3, Vector4, Quaternion, ApproxEq };
+
+#[test]
+fn constants() {
+    assert_eq!(Matrix4x4::IDENTITY * Matrix4x4::IDENTITY, Matrix4x4::IDENTITY);
+    assert_eq!(Matrix4x4::IDENTITY * Matrix4x4::ZERO, Matrix4x4::ZERO);
+}
+
+#[test]
+fn new() {
+    let m = Matrix4x4::new(
+        Vector4::new(1.0, 2.0, 3.0, 4.0),
+        Vector4::new(5.0, 6.0, 7.0, 8.0),
+        Vector4::new(9.0, 10.0, 11.0, 12.0),
+        Vector4::new(13.0, 14.0, 15.0, 16.0)
+    );
+
+    assert_eq!(m.column(1), Vector4::new(5.0, 6.0, 7.0, 8.0));
+    assert_eq!(m.row(1), Vector4::new(2.0, 6.0, 10.0, 14.0));
+    assert_eq!(m.transpose().column(1), Vector4::new(2.0, 6.0, 10.0, 14.0));
+}
+
+#[test]
+fn make_from_translation() {
+    let m = Matrix4x4::make_from_translation(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(m.transform_point(Vector3::ZERO), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(m.transform_direction(Vector3::UP), Vector3::UP);
+}
+
+#[test]
+fn make_from_scale() {
+    let m = Matrix4x4::make_from_scale(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(m.transform_point(Vector3::ONE), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn make_from_rotation() {
+    let q = Quaternion::from_angle_axis(90.0 * DEG2RAD, Vector3::UP);
+    let m = Matrix4x4::make_from_rotation(q);
+
+    assert_approx_eq!(m.transform_direction(Vector3::FORWARD), q * Vector3::FORWARD);
+    assert_approx_eq!(m.transform_direction(Vector3::RIGHT), q * Vector3::RIGHT);
+}
+
+#[test]
+fn make_from_trs() {
+    let q = Quaternion::from_angle_axis(90.0 * DEG2RAD, Vector3::UP);
+    let m = Matrix4x4::make_from_trs(Vector3::UP, q, Vector3::ONE * 2.0);
+
+    let expected = Matrix4x4::make_from_translation(Vector3::UP) *
+        Matrix4x4::make_from_rotation(q) *
+        Matrix4x4::make_from_scale(Vector3::ONE * 2.0);
+
+    assert_approx_eq!(m, expected);
+    assert_approx_eq!(m.transform_point(Vector3::FORWARD), Vector3::new(2.0, 1.0, 0.0));
+}
+
+#[test]
+fn mul_vector() {
+    let m = Matrix4x4::make_from_translation(Vector3::new(1.0, 2.0, 3.0));
+
+    assert_eq!(m * Vector4::new(0.0, 0.0, 0.0, 1.0), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(m * Vector4::new(1.0, 0.0, 0.0, 0.0), Vector4::new(1.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+fn determinant() {
+    assert_approx_eq!(Matrix4x4::IDENTITY.determinant(), 1.0);
+    assert_approx_eq!(Matrix4x4::make_from_scale(Vector3::new(1.0, 2.0, 3.0)).determinant(), 6.0);
+}
+
+#[test]
+fn inverse() {
+    let m = Matrix4x4::new(
+        Vector4::new(2.0, 0.0, 1.0, 0.0),
+        Vector4::new(1.0, 3.0, 0.0, 0.0),
+        Vector4::new(0.0, 1.0, 4.0, 1.0),
+        Vector4::new(1.0, 0.0, 0.0, 2.0)
+    );
+
+    assert_eq!(m * m.inverse(), Matrix4x4::IDENTITY);
+    assert_eq!(m.inverse() * m, Matrix4x4::IDENTITY);
+    assert_eq!(Matrix4x4::IDENTITY.inverse(), Matrix4x4::IDENTITY);
+}
+
+#[test]
+fn try_inverse() {
+    let m = Matrix4x4::make_from_trs(Vector3::new(1.0, 2.0, 3.0), Quaternion::from_euler(Vector3::new(0.2, 0.4, 0.6)), Vector3::ONE);
+    assert_eq!(m.try_inverse(), Ok(m.inverse()));
+
+    // Tiny but valid scale
+    let small = Matrix4x4::make_from_scale(Vector3::new(0.001, 0.001, 0.001));
+    assert!(small.try_inverse().is_ok());
+
+    let singular = Matrix4x4::make_from_scale(Vector3::new(1.0, 0.0, 1.0));
+    assert_eq!(singular.try_inverse(), Err(MathError::Singular));
+    assert_eq!(Matrix4x4::ZERO.try_inverse(), Err(MathError::Singular));
+
+    let mut nan = Matrix4x4::IDENTITY;
+    nan.m12 = f32::NAN;
+    assert_eq!(nan.try_inverse(), Err(MathError::NonFinite));
+}
+
+#[test]
+fn index() {
+    let mut m = Matrix4x4::make_from_translation(Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(m[(0, 3)], 1.0);
+    assert_eq!(m[(1, 3)], 2.0);
+    assert_eq!(m[(2, 3)], 3.0);
+    assert_eq!(m[(3, 3)], 1.0);
+
+    m[(0, 3)] = 5.0;
+    assert_eq!(m.m03, 5.0);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_range() {
+    let m = Matrix4x4::IDENTITY;
+    let _ = m[(0, 4)];
+}
+
+#[test]
+fn array_conversions() {
+    let m = Matrix4x4::make_from_translation(Vector3::new(1.0, 2.0, 3.0));
+
+    let a: [f32; 16] = m.into();
+    assert_eq!(a[3], 1.0);
+    assert_eq!(a[7], 2.0);
+    assert_eq!(a[11], 3.0);
+    assert_eq!(Matrix4x4::from(a), m);
+    assert_eq!(m.as_ref(), &a[..]);
+    assert_eq!(Matrix4x4::from_slice(&a), m);
+
+    let rows: [[f32; 4]; 4] = m.into();
+    assert_eq!(rows[0], [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(Matrix4x4::from(rows), m);
+}
+
+#[test]
+fn tuple_conversions() {
+    let m = Matrix4x4::make_from_translation(Vector3::new(1.0, 2.0, 3.0));
+
+    let (c0, c1, c2, c3): (Vector4, Vector4, Vector4, Vector4) = m.into();
+    assert_eq!(c0, Vector4::new(1.0, 0.0, 0.0, 0.0));
+    assert_eq!(c3, Vector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(Matrix4x4::from((c0, c1, c2, c3)), m);
+}
+
+#[test]
+fn from_matrix3x3() {
+    let m = Matrix4x4::from(Matrix3x3::make_from_scale(Vector3::new(2.0, 3.0, 4.0)));
+
+    assert_eq!(m, Matrix4x4::make_from_scale(Vector3::new(2.0, 3.0, 4.0)));
+    assert_eq!(m.row(3), Vector4::new(0.0, 0.0, 0.0, 1.0));
+}
@@ -1,6 +1,36 @@
 mod approx_eq;
 mod clamp;
+mod error;
+mod edge_cases;
+mod math;
+mod vector2;
 mod vector3;
 mod vector4;
 mod quaternion;
-mod matrix4x4;
\ No newline at end of file
+mod dual_quaternion;
+mod matrix3x2;
+mod matrix3x3;
+mod matrix4x4;
+mod affine3;
+mod transform;
+mod ray;
+mod viewport;
+mod fixed;
+mod fixed_vector3;
+mod fixed_quaternion;
+mod mask;
+mod vector3_wide;
+mod batch;
+#[cfg(feature = "swizzle")]
+mod swizzle;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(any(feature = "mint", feature = "glam", feature = "nalgebra", feature = "cgmath"))]
+mod interop;
+#[cfg(feature = "deterministic")]
+mod deterministic;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
+mod properties;
\ No newline at end of file
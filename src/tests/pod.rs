@@ -0,0 +1,51 @@
+use pod;
+use { Vector3, Vector4, Matrix4x4 };
+
+#[test]
+fn as_bytes() {
+    let vertices = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+    let bytes = pod::as_bytes(&vertices);
+
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(&bytes[..4], &1.0f32.to_ne_bytes());
+    assert_eq!(&bytes[20..], &6.0f32.to_ne_bytes());
+}
+
+#[test]
+fn as_bytes_mut() {
+    let mut values = [Vector4::ZERO];
+    pod::as_bytes_mut(&mut values)[12..].copy_from_slice(&1.0f32.to_ne_bytes());
+
+    assert_eq!(values[0], Vector4::new(0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn from_bytes() {
+    let matrices = [Matrix4x4::IDENTITY, Matrix4x4::make_from_translation(Vector3::ONE)];
+    let round_trip: &[Matrix4x4] = pod::from_bytes(pod::as_bytes(&matrices));
+
+    assert_eq!(round_trip, &matrices[..]);
+}
+
+#[test]
+fn try_from_bytes() {
+    let values = [Vector4::ONE];
+    let bytes = pod::as_bytes(&values);
+
+    assert!(pod::try_from_bytes::<Vector4>(bytes).is_some());
+    assert!(pod::try_from_bytes::<Vector3>(bytes).is_none());
+}
+
+#[test]
+fn as_floats() {
+    let vertices = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+
+    assert_eq!(pod::as_floats(&vertices), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+}
+
+#[test]
+fn zeroed() {
+    let m: Matrix4x4 = ::bytemuck::Zeroable::zeroed();
+
+    assert_eq!(m, Matrix4x4::ZERO);
+}
@@ -0,0 +1,133 @@
+// Algebraic identities checked over generated inputs
+use proptest::prelude::*;
+
+use consts::{ PI };
+use strategies::*;
+use { Matrix4x4, Quaternion, Vector3 };
+
+fn close(a: &[f32], b: &[f32], tolerance: f32) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() <= tolerance)
+}
+
+// q and -q describe the same rotation
+fn same_rotation(a: Quaternion, b: Quaternion, tolerance: f32) -> bool {
+    Quaternion::dot(a, b).abs() >= 1.0 - tolerance
+}
+
+proptest! {
+    #[test]
+    fn quaternion_inverse(q in quaternion(10.0).prop_filter("near zero", |q| q.magnitude() > 0.1)) {
+        let identity = Quaternion::IDENTITY;
+
+        prop_assert!(close((q * q.inverse()).as_ref(), identity.as_ref(), 1e-5));
+        prop_assert!(close((q.inverse() * q).as_ref(), identity.as_ref(), 1e-5));
+    }
+
+    #[test]
+    fn unit_quaternion_inverse_is_conjugate(q in unit_quaternion()) {
+        prop_assert!(close(q.inverse().as_ref(), q.conjugate().as_ref(), 1e-6));
+    }
+
+    #[test]
+    fn rotation_preserves_length(q in unit_quaternion(), v in vector3(100.0)) {
+        let tolerance = 1e-5 * v.magnitude().max(1.0);
+
+        prop_assert!(((q * v).magnitude() - v.magnitude()).abs() <= tolerance);
+        prop_assert!((q.rotate_unit(v).magnitude() - v.magnitude()).abs() <= tolerance);
+    }
+
+    #[test]
+    fn rotation_composes(a in unit_quaternion(), b in unit_quaternion(), v in vector3(10.0)) {
+        prop_assert!(close((a * b * v).as_ref(), (a * (b * v)).as_ref(), 1e-4));
+    }
+
+    #[test]
+    fn slerp_endpoints(a in unit_quaternion(), b in unit_quaternion()) {
+        prop_assert!(same_rotation(Quaternion::slerp(a, b, 0.0), a, 1e-5));
+        prop_assert!(same_rotation(Quaternion::slerp(a, b, 1.0), b, 1e-5));
+    }
+
+    #[test]
+    fn slerp_stays_normalized(a in unit_quaternion(), b in unit_quaternion(), t in 0.0f32..1.0) {
+        prop_assert!((Quaternion::slerp(a, b, t).magnitude() - 1.0).abs() <= 1e-4);
+    }
+
+    #[test]
+    fn euler_round_trip(e in euler()) {
+        let q = Quaternion::from_euler(e);
+
+        prop_assert!((q.magnitude() - 1.0).abs() <= 1e-5);
+        prop_assert!(close(q.to_euler().as_ref(), e.as_ref(), 1e-3), "{} != {}", q.to_euler(), e);
+    }
+
+    #[test]
+    fn quaternion_euler_round_trip(q in unit_quaternion()) {
+        let e = q.to_euler();
+
+        // Near the singularity the decomposition is not unique, only the rotation has to survive
+        prop_assert!(same_rotation(Quaternion::from_euler(e), q, 1e-4));
+    }
+
+    // unit_quaternion almost never lands exactly on the singularity, this samples it directly
+    #[test]
+    fn quaternion_euler_round_trip_at_poles(north in any::<bool>(), e in euler()) {
+        let x = if north { PI / 2.0 } else { -PI / 2.0 };
+        let q = Quaternion::from_euler(Vector3::new(x, e.y, e.z));
+
+        prop_assert!(same_rotation(Quaternion::from_euler(q.to_euler()), q, 1e-4));
+    }
+
+    #[test]
+    fn matrix_inverse(m in trs_matrix4x4()) {
+        let identity = Matrix4x4::IDENTITY;
+
+        prop_assert!(close((m * m.inverse()).as_ref(), identity.as_ref(), 1e-3));
+        prop_assert!(close((m.inverse() * m).as_ref(), identity.as_ref(), 1e-3));
+    }
+
+    #[test]
+    fn matrix_transpose_is_involution(m in matrix4x4(100.0)) {
+        let t = m.transpose().transpose();
+
+        prop_assert_eq!(t.as_ref(), m.as_ref());
+    }
+
+    #[test]
+    fn matrix_rotation_matches_quaternion(q in unit_quaternion(), v in vector3(10.0)) {
+        let m = Matrix4x4::make_from_rotation(q);
+
+        prop_assert!(close(m.transform_direction(v).as_ref(), (q * v).as_ref(), 1e-4));
+    }
+
+    #[test]
+    fn vector3_normalization_is_idempotent(v in vector3(100.0).prop_filter("near zero", |v| v.magnitude() > 1e-3)) {
+        let n = v.normalized();
+
+        prop_assert!((n.magnitude() - 1.0).abs() <= 1e-6);
+        prop_assert!(close(n.normalized().as_ref(), n.as_ref(), 1e-6));
+    }
+
+    #[test]
+    fn vector4_normalization_is_idempotent(v in vector4(100.0).prop_filter("near zero", |v| v.magnitude() > 1e-3)) {
+        let n = v.normalized();
+
+        prop_assert!((n.magnitude() - 1.0).abs() <= 1e-6);
+        prop_assert!(close(n.normalized().as_ref(), n.as_ref(), 1e-6));
+    }
+
+    #[test]
+    fn quaternion_normalization_is_idempotent(q in quaternion(100.0).prop_filter("near zero", |q| q.magnitude() > 1e-3)) {
+        let n = q.normalized();
+
+        prop_assert!((n.magnitude() - 1.0).abs() <= 1e-6);
+        prop_assert!(close(n.normalized().as_ref(), n.as_ref(), 1e-6));
+    }
+
+    #[test]
+    fn cross_is_orthogonal(a in unit_vector3(), b in unit_vector3()) {
+        let c = Vector3::cross(a, b);
+
+        prop_assert!(Vector3::dot(c, a).abs() <= 1e-5);
+        prop_assert!(Vector3::dot(c, b).abs() <= 1e-5);
+    }
+}
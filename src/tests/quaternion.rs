@@ -1,5 +1,7 @@
-use consts::{ DEG2RAD };
-use {Vector3, Quaternion, ApproxEq};
+#![allow(clippy::approx_constant)]
+
+use consts::{ DEG2RAD, PI };
+use {MathError, Matrix4x4, Vector3, Quaternion, ApproxEq};
 
 const RIGHT_QUAT: Quaternion = Quaternion{ x: 0.0, y: 0.7071068, z: 0.0, w: 0.7071068 };
 const LEFT_QUAT: Quaternion = Quaternion{ x: 0.0, y: -0.7071068, z: 0.0, w: 0.7071068 };
@@ -84,7 +86,7 @@ fn to_angle_axis() {
 
     RIGHT_QUAT.to_angle_axis(&mut angle, &mut axis);
 
-    assert_approx_eq!(angle, 90.0 * DEG2RAD);
+    assert!((angle - 90.0 * DEG2RAD).abs() < 1e-6);
     assert_approx_eq!(axis, Vector3::UP);
 }
 
@@ -150,6 +152,61 @@ fn normalize() {
     assert_approx_eq!(q_norm.magnitude(), 1.0);
 }
 
+#[test]
+fn try_normalized() {
+    assert_approx_eq!((RIGHT_QUAT * 3.0).try_normalized().unwrap().magnitude(), 1.0);
+    assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).try_normalized(), Err(MathError::ZeroLength));
+    assert_eq!(Quaternion::new(f32::NAN, 0.0, 0.0, 1.0).try_normalized(), Err(MathError::NonFinite));
+
+    // The length of finite components can overflow, they still normalize
+    let q = Quaternion::new(1e20, 0.0, 0.0, 1e20).try_normalized().unwrap();
+    assert_approx_eq!(q.x, 0.70710677);
+    assert_approx_eq!(q.w, 0.70710677);
+}
+
+#[test]
+fn normalize_or() {
+    let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(zero.normalized_or(Quaternion::IDENTITY), Quaternion::IDENTITY);
+
+    let mut q = RIGHT_QUAT * 2.0;
+    q.normalize_or(Quaternion::IDENTITY);
+    assert_approx_eq!(q.magnitude(), 1.0);
+}
+
+#[test]
+fn try_inverse() {
+    assert_eq!(RIGHT_QUAT.try_inverse(), Ok(RIGHT_QUAT.inverse()));
+    assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).try_inverse(), Err(MathError::Singular));
+    assert_eq!(Quaternion::new(0.0, f32::INFINITY, 0.0, 1.0).try_inverse(), Err(MathError::NonFinite));
+
+    // The squared norm of these overflows and underflows, the inverse itself is representable
+    let large = Quaternion::new(1e20, 0.0, 0.0, 1e20);
+    let inverse = large.try_inverse().unwrap();
+    assert_approx_eq!(inverse.x * 1e20, -0.5);
+    assert_approx_eq!(inverse.w * 1e20, 0.5);
+    assert_approx_eq!((large * inverse).w, 1.0);
+    assert_approx_eq!(Quaternion::new(0.0, 0.0, 0.0, 1e-20).try_inverse().unwrap().w * 1e-20, 1.0);
+
+    // 1 / 1e-45 does not fit in an f32
+    assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 1e-45).try_inverse(), Err(MathError::Singular));
+}
+
+#[test]
+fn try_from_orientation() {
+    assert_eq!(Quaternion::try_from_orientation(Vector3::RIGHT, Vector3::UP), Ok(Quaternion::from_orientation(Vector3::RIGHT, Vector3::UP)));
+    assert_eq!(Quaternion::try_from_orientation(Vector3::ZERO, Vector3::UP), Err(MathError::ZeroLength));
+    assert_eq!(Quaternion::try_from_orientation(Vector3::FORWARD, Vector3::ZERO), Err(MathError::ZeroLength));
+    assert_eq!(Quaternion::try_from_orientation(Vector3::UP * 2.0, Vector3::UP), Err(MathError::DegenerateBasis));
+    assert_eq!(Quaternion::try_look_rotation(Vector3::new(f32::NAN, 0.0, 1.0), Vector3::UP), Err(MathError::NonFinite));
+}
+
+#[test]
+fn try_from_to_rotation() {
+    assert_eq!(Quaternion::try_from_to_rotation(Vector3::FORWARD, Vector3::RIGHT), Ok(Quaternion::from_to_rotation(Vector3::FORWARD, Vector3::RIGHT)));
+    assert_eq!(Quaternion::try_from_to_rotation(Vector3::ZERO, Vector3::RIGHT), Err(MathError::ZeroLength));
+}
+
 #[test]
 fn lerp() {
     let q0 = LEFT_QUAT;
@@ -186,6 +243,20 @@ fn slerp_unclamped() {
     assert_approx_eq!(q2, Quaternion::slerp(q0, q2, 1.0).normalized());
 }
 
+// -to is the same rotation as to, slerp has to take the short arc instead of going the long way around
+#[test]
+fn slerp_opposite_hemisphere() {
+    let from = Quaternion::IDENTITY;
+    let to = Quaternion::from_angle_axis(0.5, Vector3::UP) * -1.0;
+    let q = Quaternion::slerp_unclamped(from, to, 0.5);
+    let expected = Quaternion::from_angle_axis(0.25, Vector3::UP);
+
+    assert_approx_eq!(q.x, expected.x);
+    assert_approx_eq!(q.y, expected.y);
+    assert_approx_eq!(q.z, expected.z);
+    assert_approx_eq!(q.w, expected.w);
+}
+
 #[test]
 fn add_quaternion() {
     let q = RIGHT_QUAT + RIGHT_QUAT;
@@ -227,4 +298,236 @@ fn mul_quaternion_scalar() {
     let q_scaled = q * 2.0;
 
     assert_approx_eq!(q_scaled, Quaternion::new(0.0, 0.0, 0.0, 2.0));
-}
\ No newline at end of file
+}
+
+#[test]
+fn angle_between() {
+    assert!((Quaternion::angle_between(Quaternion::IDENTITY, RIGHT_QUAT) - 90.0 * DEG2RAD).abs() < 1e-6);
+    assert!((Quaternion::angle_between(LEFT_QUAT, RIGHT_QUAT) - 180.0 * DEG2RAD).abs() < 1e-6);
+}
+
+#[test]
+fn exp_ln() {
+    let q = Quaternion::from_angle_axis(60.0 * DEG2RAD, Vector3::new(1.0, 2.0, 3.0).normalized());
+
+    assert_approx_eq!(q.ln().exp(), q);
+    assert_approx_eq!(Quaternion::IDENTITY.ln().exp(), Quaternion::IDENTITY);
+}
+
+#[test]
+fn pow() {
+    let half = RIGHT_QUAT.pow(0.5);
+
+    assert_approx_eq!(half, Quaternion::from_angle_axis(45.0 * DEG2RAD, Vector3::UP));
+    assert_approx_eq!(half * half, RIGHT_QUAT);
+    assert_approx_eq!(RIGHT_QUAT.pow(0.0), Quaternion::IDENTITY);
+}
+
+#[test]
+fn squad() {
+    let q0 = LEFT_QUAT;
+    let q1 = Quaternion::IDENTITY;
+    let q2 = RIGHT_QUAT;
+    let q3 = Quaternion::from_angle_axis(90.0 * DEG2RAD, Vector3::RIGHT);
+
+    let s1 = Quaternion::squad_tangent(q0, q1, q2);
+    let s2 = Quaternion::squad_tangent(q1, q2, q3);
+
+    assert_approx_eq!(Quaternion::squad(q1, q2, s1, s2, 0.0), q1);
+    assert_approx_eq!(Quaternion::squad(q1, q2, s1, s2, 1.0), q2);
+}
+
+#[test]
+fn squad_continuous_velocity() {
+    let keys = [
+        LEFT_QUAT,
+        Quaternion::IDENTITY,
+        Quaternion::from_angle_axis(90.0 * DEG2RAD, Vector3::RIGHT),
+        RIGHT_QUAT
+    ];
+
+    let s1 = Quaternion::squad_tangent(keys[0], keys[1], keys[2]);
+    let s2 = Quaternion::squad_tangent(keys[1], keys[2], keys[3]);
+    let s0 = Quaternion::squad_tangent(keys[0], keys[0], keys[1]);
+
+    // Angular velocity arriving at and leaving keys[1] should match, dividing the small rotations by the step
+    // keeps the comparison relative to the velocity instead of to a rotation of dt
+    let dt = 0.001;
+    let before = Quaternion::squad(keys[0], keys[1], s0, s1, 1.0 - dt);
+    let after = Quaternion::squad(keys[1], keys[2], s1, s2, dt);
+
+    let velocity = |from: Quaternion, to: Quaternion| {
+        let l = (to * from.conjugate()).ln();
+        Vector3::new(l.x, l.y, l.z) / dt
+    };
+
+    let incoming = velocity(before, keys[1]);
+    let outgoing = velocity(keys[1], after);
+
+    assert!(incoming.magnitude() > 0.1);
+    assert!((incoming - outgoing).magnitude() < 1e-2 * incoming.magnitude());
+
+    // Piecewise slerp through the same keys turns a corner at keys[1]
+    let before = Quaternion::slerp(keys[0], keys[1], 1.0 - dt);
+    let after = Quaternion::slerp(keys[1], keys[2], dt);
+
+    let incoming = velocity(before, keys[1]);
+    let outgoing = velocity(keys[1], after);
+
+    assert!((incoming - outgoing).magnitude() > 0.5 * incoming.magnitude());
+}
+
+#[test]
+fn from_to_rotation() {
+    let q = Quaternion::from_to_rotation(Vector3::FORWARD, Vector3::RIGHT);
+    assert_approx_eq!(q, RIGHT_QUAT);
+
+    let q_same = Quaternion::from_to_rotation(Vector3::UP, Vector3::UP * 2.0);
+    assert_approx_eq!(q_same, Quaternion::IDENTITY);
+
+    let q_opposite = Quaternion::from_to_rotation(Vector3::FORWARD, -Vector3::FORWARD);
+    assert_approx_eq!(q_opposite * Vector3::FORWARD, -Vector3::FORWARD);
+
+    let q_opposite_right = Quaternion::from_to_rotation(Vector3::RIGHT, -Vector3::RIGHT);
+    assert_approx_eq!(q_opposite_right * Vector3::RIGHT, -Vector3::RIGHT);
+}
+
+#[test]
+fn look_rotation() {
+    let q = Quaternion::look_rotation(Vector3::RIGHT, Vector3::UP);
+    assert_approx_eq!(q, RIGHT_QUAT);
+
+    let q_parallel = Quaternion::look_rotation(Vector3::UP, Vector3::UP);
+    assert!(!q_parallel.x.is_nan() && !q_parallel.y.is_nan() && !q_parallel.z.is_nan() && !q_parallel.w.is_nan());
+    assert_approx_eq!(q_parallel.forward(), Vector3::UP);
+
+    let q_down = Quaternion::from_orientation(-Vector3::UP, Vector3::UP);
+    assert_approx_eq!(q_down.forward(), -Vector3::UP);
+}
+
+#[test]
+fn rotate_towards() {
+    let q = Quaternion::rotate_towards(Quaternion::IDENTITY, RIGHT_QUAT, 45.0 * DEG2RAD);
+    assert_approx_eq!(q, Quaternion::from_angle_axis(45.0 * DEG2RAD, Vector3::UP));
+
+    let q_overshoot = Quaternion::rotate_towards(Quaternion::IDENTITY, RIGHT_QUAT, PI);
+    assert_approx_eq!(q_overshoot, RIGHT_QUAT);
+}
+
+#[test]
+fn derivative() {
+    let omega = Vector3::UP * (90.0 * DEG2RAD);
+    let dq = Quaternion::IDENTITY.derivative(omega);
+
+    assert_approx_eq!(dq.x, 0.0);
+    assert_approx_eq!(dq.y, 45.0 * DEG2RAD);
+    assert_approx_eq!(dq.z, 0.0);
+    assert_approx_eq!(dq.w, 0.0);
+}
+
+#[test]
+fn integrate() {
+    let omega = Vector3::UP * (90.0 * DEG2RAD);
+    let mut q = Quaternion::IDENTITY;
+    for _ in 0..1000 {
+        q = q.integrate(omega, 0.001);
+    }
+
+    assert_approx_eq!(q.magnitude(), 1.0);
+    assert!(Quaternion::angle_between(q, RIGHT_QUAT) < 1e-3);
+}
+
+#[test]
+fn integrate_exact() {
+    let omega = Vector3::UP * (90.0 * DEG2RAD);
+    let q = Quaternion::IDENTITY.integrate_exact(omega, 1.0);
+
+    assert_approx_eq!(q, RIGHT_QUAT);
+    assert_approx_eq!(q.integrate_exact(Vector3::ZERO, 1.0), RIGHT_QUAT);
+}
+
+#[test]
+fn angular_velocity() {
+    let omega = Vector3::new(0.3, -1.2, 0.5);
+    let from = Quaternion::from_angle_axis(30.0 * DEG2RAD, Vector3::RIGHT);
+    let to = from.integrate_exact(omega, 0.5);
+
+    let measured = Quaternion::angular_velocity(from, to, 0.5);
+
+    assert!((measured - omega).magnitude() < 1e-4);
+}
+
+#[test]
+fn conversions() {
+    let q = Quaternion::from([0.0, 0.7071068, 0.0, 0.7071068]);
+    assert_eq!(q, RIGHT_QUAT);
+    assert_eq!(q[1], 0.7071068);
+    assert_eq!(q[3], 0.7071068);
+    assert_eq!(Quaternion::from((0.0, 0.7071068, 0.0, 0.7071068)), RIGHT_QUAT);
+    assert_eq!(Quaternion::from_slice(RIGHT_QUAT.as_ref()), RIGHT_QUAT);
+
+    let a: [f32; 4] = Quaternion::IDENTITY.into();
+    assert_eq!(a, [0.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn rotation_matches_matrix() {
+    let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(-7.5, 0.25, 4.0), Vector3::new(100.0, -50.0, 0.001)];
+
+    for i in 0..64 {
+        let i = i as f32;
+        let q = Quaternion::from_euler(Vector3::new(i * 0.37, i * -0.91, i * 1.53)).normalized();
+        let m = Matrix4x4::make_from_rotation(q);
+
+        for v in vectors.iter() {
+            let expected = m.transform_direction(*v);
+            let tolerance = 1e-5 * v.magnitude();
+
+            assert!(Vector3::distance(q * *v, expected) <= tolerance, "{} != {}", q * *v, expected);
+            assert!(Vector3::distance(q.rotate_unit(*v), expected) <= tolerance, "{} != {}", q.rotate_unit(*v), expected);
+        }
+    }
+}
+
+#[test]
+fn rotation_by_non_unit_quaternion_scales() {
+    let q = Quaternion::from_euler(Vector3::new(0.3, 0.6, 0.9)).normalized();
+    let v = Vector3::new(1.0, -2.0, 0.5);
+
+    assert!(Vector3::distance((q * 2.0) * v, (q * v) * 4.0) < 1e-5);
+}
+
+// Combined angles rotate about up by y, then the local forward axis by x and the local right axis by z
+#[test]
+fn from_euler_combined_axes() {
+    let (x, y, z) = (0.3, -0.5, 0.7);
+    let q = Quaternion::from_euler_components(x, y, z);
+
+    let expected = Quaternion::from_angle_axis(y, Vector3::UP)
+        * Quaternion::from_angle_axis(x, Vector3::FORWARD)
+        * Quaternion::from_angle_axis(z, Vector3::RIGHT);
+
+    assert_approx_eq!(q.magnitude(), 1.0);
+    assert_approx_eq!(q.x, expected.x);
+    assert_approx_eq!(q.y, expected.y);
+    assert_approx_eq!(q.z, expected.z);
+    assert_approx_eq!(q.w, expected.w);
+    assert_approx_eq!(q.to_euler(), Vector3::new(x, y, z));
+}
+
+#[test]
+fn to_euler_at_the_poles() {
+    // Yaw and roll turn about the same axis at the poles and come back combined in y
+    assert_approx_eq!(Quaternion::from_euler(Vector3::new(PI / 2.0, 0.7, 0.0)).to_euler(), Vector3::new(PI / 2.0, 0.7, 0.0));
+    assert_approx_eq!(Quaternion::from_euler(Vector3::new(-PI / 2.0, 0.7, 0.0)).to_euler(), Vector3::new(-PI / 2.0, 0.7, 0.0));
+    assert_approx_eq!(Quaternion::from_euler(Vector3::new(PI / 2.0, 0.7, 0.2)).to_euler(), Vector3::new(PI / 2.0, 0.9, 0.0));
+    assert_approx_eq!(Quaternion::from_euler(Vector3::new(-PI / 2.0, 0.7, 0.2)).to_euler(), Vector3::new(-PI / 2.0, 0.5, 0.0));
+
+    // Near the pole either branch has to give back the same rotation
+    for &x in &[PI / 2.0, PI / 2.0 - 1e-3, PI / 2.0 - 1e-2, -PI / 2.0, -PI / 2.0 + 1e-3, -PI / 2.0 + 1e-2] {
+        let q = Quaternion::from_euler_components(x, -2.1, 0.4);
+        let e = q.to_euler();
+
+        assert!(Quaternion::dot(q, Quaternion::from_euler_components(e.x, e.y, e.z)).abs() > 1.0 - 1e-6);
+    }
+}
@@ -0,0 +1,16 @@
+use { Ray, Vector3 };
+
+#[test]
+fn constructor() {
+    let ray = Ray::new(Vector3::ONE, Vector3::FORWARD * 3.0);
+
+    assert_eq!(ray.origin, Vector3::ONE);
+    assert_eq!(ray.direction, Vector3::FORWARD);
+}
+
+#[test]
+fn get_point() {
+    let ray = Ray::new(Vector3::ZERO, Vector3::RIGHT);
+
+    assert_eq!(ray.get_point(2.0), Vector3::RIGHT * 2.0);
+}
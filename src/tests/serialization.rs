@@ -0,0 +1,168 @@
+use serde_test::{ assert_tokens, assert_de_tokens, assert_de_tokens_error, Configure, Token };
+
+use { Vector3, Quaternion, Matrix4x4, Transform };
+use { Fixed32, Fixed64, FixedVector3, FixedQuaternion, Vector3x4, Mask4, Mask8 };
+
+#[test]
+fn vector3_readable() {
+    assert_tokens(&Vector3::new(1.0, 2.0, 3.0).readable(), &[
+        Token::Struct { name: "Vector3", len: 3 },
+        Token::Str("x"), Token::F32(1.0),
+        Token::Str("y"), Token::F32(2.0),
+        Token::Str("z"), Token::F32(3.0),
+        Token::StructEnd
+    ]);
+}
+
+#[test]
+fn vector3_compact() {
+    assert_tokens(&Vector3::new(1.0, 2.0, 3.0).compact(), &[
+        Token::Tuple { len: 3 },
+        Token::F32(1.0), Token::F32(2.0), Token::F32(3.0),
+        Token::TupleEnd
+    ]);
+}
+
+#[test]
+fn quaternion_compact() {
+    assert_tokens(&Quaternion::IDENTITY.compact(), &[
+        Token::Tuple { len: 4 },
+        Token::F32(0.0), Token::F32(0.0), Token::F32(0.0), Token::F32(1.0),
+        Token::TupleEnd
+    ]);
+}
+
+#[test]
+fn matrix4x4_compact() {
+    let m = Matrix4x4::make_from_translation(Vector3::new(1.0, 2.0, 3.0));
+    let mut tokens = vec![Token::Tuple { len: 16 }];
+    for value in &[1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0, 1.0, 3.0, 0.0, 0.0, 0.0, 1.0] {
+        tokens.push(Token::F32(*value));
+    }
+    tokens.push(Token::TupleEnd);
+
+    assert_tokens(&m.compact(), &tokens);
+}
+
+#[test]
+fn nested_readable() {
+    assert_tokens(&Transform::from_position(Vector3::new(1.0, 2.0, 3.0)).readable(), &[
+        Token::Struct { name: "Transform", len: 3 },
+        Token::Str("position"),
+        Token::Struct { name: "Vector3", len: 3 },
+        Token::Str("x"), Token::F32(1.0),
+        Token::Str("y"), Token::F32(2.0),
+        Token::Str("z"), Token::F32(3.0),
+        Token::StructEnd,
+        Token::Str("rotation"),
+        Token::Struct { name: "Quaternion", len: 4 },
+        Token::Str("x"), Token::F32(0.0),
+        Token::Str("y"), Token::F32(0.0),
+        Token::Str("z"), Token::F32(0.0),
+        Token::Str("w"), Token::F32(1.0),
+        Token::StructEnd,
+        Token::Str("scale"),
+        Token::Struct { name: "Vector3", len: 3 },
+        Token::Str("x"), Token::F32(1.0),
+        Token::Str("y"), Token::F32(1.0),
+        Token::Str("z"), Token::F32(1.0),
+        Token::StructEnd,
+        Token::StructEnd
+    ]);
+}
+
+#[test]
+fn deserialize_any_field_order() {
+    assert_de_tokens(&Vector3::new(1.0, 2.0, 3.0).readable(), &[
+        Token::Map { len: Some(3) },
+        Token::Str("z"), Token::F32(3.0),
+        Token::Str("x"), Token::F32(1.0),
+        Token::Str("y"), Token::F32(2.0),
+        Token::MapEnd
+    ]);
+}
+
+#[test]
+fn deserialize_sequence_when_readable() {
+    assert_de_tokens(&Vector3::new(1.0, 2.0, 3.0).readable(), &[
+        Token::Seq { len: Some(3) },
+        Token::F32(1.0), Token::F32(2.0), Token::F32(3.0),
+        Token::SeqEnd
+    ]);
+}
+
+#[test]
+fn deserialize_errors() {
+    assert_de_tokens_error::<::serde_test::Readable<Vector3>>(&[
+        Token::Struct { name: "Vector3", len: 2 },
+        Token::Str("x"), Token::F32(1.0),
+        Token::Str("y"), Token::F32(2.0),
+        Token::StructEnd
+    ], "missing field `z`");
+
+    assert_de_tokens_error::<::serde_test::Readable<Vector3>>(&[
+        Token::Struct { name: "Vector3", len: 3 },
+        Token::Str("w"), Token::F32(1.0)
+    ], "unknown field `w`, expected one of `x`, `y`, `z`");
+
+    assert_de_tokens_error::<::serde_test::Compact<Vector3>>(&[
+        Token::Tuple { len: 2 },
+        Token::F32(1.0), Token::F32(2.0),
+        Token::TupleEnd
+    ], "invalid length 2, expected a Vector3 as a sequence or map of 3 fields");
+}
+
+// Raw bits keep fixed-point snapshots exact
+#[test]
+fn fixed_bits() {
+    assert_tokens(&Fixed32::from_f32(-1.5).readable(), &[Token::I32(-0x18000)]);
+    assert_tokens(&Fixed64::from_bits(-3).compact(), &[Token::I64(-3)]);
+}
+
+#[test]
+fn fixed_vector3_readable() {
+    assert_tokens(&FixedVector3::new(Fixed64::ONE, Fixed64::HALF, Fixed64::EPSILON).readable(), &[
+        Token::Struct { name: "FixedVector3", len: 3 },
+        Token::Str("x"), Token::I64(1 << 32),
+        Token::Str("y"), Token::I64(1 << 31),
+        Token::Str("z"), Token::I64(1),
+        Token::StructEnd
+    ]);
+}
+
+#[test]
+fn fixed_quaternion_compact() {
+    assert_tokens(&FixedQuaternion::IDENTITY.compact(), &[
+        Token::Tuple { len: 4 },
+        Token::I64(0), Token::I64(0), Token::I64(0), Token::I64(1 << 32),
+        Token::TupleEnd
+    ]);
+}
+
+#[test]
+fn vector3x4_compact() {
+    let wide = Vector3x4::new([1.0, 2.0, 3.0, 4.0], [0.0; 4], [-1.0; 4]);
+    let mut tokens = vec![Token::Tuple { len: 3 }];
+    for lanes in &[[1.0, 2.0, 3.0, 4.0], [0.0; 4], [-1.0; 4]] {
+        tokens.push(Token::Tuple { len: 4 });
+        tokens.extend(lanes.iter().map(|&x| Token::F32(x)));
+        tokens.push(Token::TupleEnd);
+    }
+    tokens.push(Token::TupleEnd);
+
+    assert_tokens(&wide.compact(), &tokens);
+}
+
+#[test]
+fn masks() {
+    assert_tokens(&Mask4::new([true, false, false, true]).readable(), &[
+        Token::Tuple { len: 4 },
+        Token::Bool(true), Token::Bool(false), Token::Bool(false), Token::Bool(true),
+        Token::TupleEnd
+    ]);
+
+    let mut tokens = vec![Token::Tuple { len: 8 }];
+    tokens.extend([false; 8].iter().map(|&b| Token::Bool(b)));
+    tokens.push(Token::TupleEnd);
+    assert_tokens(&Mask8::NONE.compact(), &tokens);
+}
@@ -0,0 +1,155 @@
+// Randomized checks of the SSE kernels against the scalar formulas they replace
+use simd;
+use { Matrix4x4, Quaternion, Vector4 };
+
+const ITERATIONS: usize = 1000;
+
+// Small LCG so the inputs are reproducible without pulling in a random number crate
+struct Lcg(u64);
+
+impl Lcg {
+    fn next_f32(&mut self, range: f32) -> f32 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.0 >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0) * range
+    }
+
+    fn vector4(&mut self) -> Vector4 {
+        Vector4::new(self.next_f32(10.0), self.next_f32(10.0), self.next_f32(10.0), self.next_f32(10.0))
+    }
+
+    fn quaternion(&mut self) -> Quaternion {
+        Quaternion::new(self.next_f32(1.0), self.next_f32(1.0), self.next_f32(1.0), self.next_f32(1.0))
+    }
+
+    fn matrix(&mut self) -> Matrix4x4 {
+        Matrix4x4::new(self.vector4(), self.vector4(), self.vector4(), self.vector4())
+    }
+}
+
+fn bits(v: &[f32]) -> Vec<u32> {
+    v.iter().map(|x| x.to_bits()).collect()
+}
+
+fn scalar_dot(a: Vector4, b: Vector4) -> f32 {
+    a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
+}
+
+#[test]
+fn vector4_ops() {
+    let mut rng = Lcg(1);
+    for _ in 0..ITERATIONS {
+        let a = rng.vector4();
+        let b = rng.vector4();
+        let s = rng.next_f32(10.0);
+
+        assert_eq!(bits(simd::add(a, b).as_ref()), bits(&[a.x + b.x, a.y + b.y, a.z + b.z, a.w + b.w]));
+        assert_eq!(bits(simd::sub(a, b).as_ref()), bits(&[a.x - b.x, a.y - b.y, a.z - b.z, a.w - b.w]));
+        assert_eq!(bits(simd::mul(a, b).as_ref()), bits(&[a.x * b.x, a.y * b.y, a.z * b.z, a.w * b.w]));
+        assert_eq!(bits(simd::div(a, b).as_ref()), bits(&[a.x / b.x, a.y / b.y, a.z / b.z, a.w / b.w]));
+        assert_eq!(bits(simd::scale(a, s).as_ref()), bits(&[a.x * s, a.y * s, a.z * s, a.w * s]));
+        assert_eq!(bits(simd::div_scalar(a, s).as_ref()), bits(&[a.x / s, a.y / s, a.z / s, a.w / s]));
+    }
+}
+
+#[cfg(all(target_feature = "sse4.1", not(feature = "deterministic")))]
+#[test]
+fn vector4_dot() {
+    let mut rng = Lcg(2);
+    for _ in 0..ITERATIONS {
+        let a = rng.vector4();
+        let b = rng.vector4();
+
+        assert!((simd::dot(a, b) - scalar_dot(a, b)).abs() <= 1e-4);
+    }
+}
+
+#[test]
+fn quaternion_mul() {
+    let mut rng = Lcg(3);
+    for _ in 0..ITERATIONS {
+        let a = rng.quaternion();
+        let b = rng.quaternion();
+
+        let expected = [
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z
+        ];
+
+        assert_eq!(bits(simd::quaternion_mul(a, b).as_ref()), bits(&expected));
+    }
+}
+
+#[test]
+fn matrix_mul() {
+    let mut rng = Lcg(4);
+    for _ in 0..ITERATIONS {
+        let a = rng.matrix();
+        let b = rng.matrix();
+
+        let mut expected = [0.0; 16];
+        for row in 0..4 {
+            for column in 0..4 {
+                expected[row * 4 + column] = scalar_dot(a.row(row), b.column(column));
+            }
+        }
+
+        assert_eq!(bits(simd::matrix_mul(&a, &b).as_ref()), bits(&expected));
+    }
+}
+
+#[test]
+fn matrix_mul_vector() {
+    let mut rng = Lcg(5);
+    for _ in 0..ITERATIONS {
+        let m = rng.matrix();
+        let v = rng.vector4();
+
+        let expected = [scalar_dot(m.row(0), v), scalar_dot(m.row(1), v), scalar_dot(m.row(2), v), scalar_dot(m.row(3), v)];
+
+        assert_eq!(bits(simd::matrix_mul_vector(&m, v).as_ref()), bits(&expected));
+    }
+}
+
+#[test]
+fn matrix_transpose() {
+    let mut rng = Lcg(6);
+    for _ in 0..ITERATIONS {
+        let m = rng.matrix();
+        let t = simd::transpose(&m);
+
+        for row in 0..4 {
+            for column in 0..4 {
+                assert_eq!(t[(row, column)].to_bits(), m[(column, row)].to_bits());
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+#[test]
+fn matrix_inverse() {
+    let mut rng = Lcg(7);
+    for _ in 0..ITERATIONS {
+        // Diagonally dominant so the matrix stays well conditioned
+        let mut m = rng.matrix();
+        for i in 0..4 {
+            m[(i, i)] += 50.0;
+        }
+
+        let actual = simd::inverse(&m);
+        let expected = m.cofactor_inverse();
+        for (a, e) in actual.as_ref().iter().zip(expected.as_ref()) {
+            assert!((a - e).abs() <= 1e-6, "{:?} != {:?}", actual, expected);
+        }
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+#[test]
+fn matrix_inverse_singular() {
+    let m = Matrix4x4::new(Vector4::ONE, Vector4::ONE, Vector4::ONE, Vector4::ONE);
+
+    assert!(simd::inverse(&m).as_ref().iter().any(|x| !x.is_finite()));
+}
@@ -0,0 +1,33 @@
+use { Vector2, Vector3, Vector4 };
+
+#[test]
+fn vector2() {
+    let v = Vector2::new(1.0, 2.0);
+
+    assert_eq!(v.yx(), Vector2::new(2.0, 1.0));
+    assert_eq!(v.xxy(), Vector3::new(1.0, 1.0, 2.0));
+    assert_eq!(v.xy0(), Vector3::new(1.0, 2.0, 0.0));
+    assert_eq!(v.xy01(), Vector4::new(1.0, 2.0, 0.0, 1.0));
+}
+
+#[test]
+fn vector3() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(v.xz(), Vector2::new(1.0, 3.0));
+    assert_eq!(v.zyx(), Vector3::new(3.0, 2.0, 1.0));
+    assert_eq!(v.xy0(), Vector3::new(1.0, 2.0, 0.0));
+    assert_eq!(v.xyz1(), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(v.zzzz(), Vector4::new(3.0, 3.0, 3.0, 3.0));
+}
+
+#[test]
+fn vector4() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(v.xy(), Vector2::new(1.0, 2.0));
+    assert_eq!(v.wzy(), Vector3::new(4.0, 3.0, 2.0));
+    assert_eq!(v.xyzw(), v);
+    assert_eq!(v.wzyx(), Vector4::new(4.0, 3.0, 2.0, 1.0));
+    assert_eq!(v.xyz0(), Vector4::new(1.0, 2.0, 3.0, 0.0));
+}
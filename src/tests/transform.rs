@@ -0,0 +1,106 @@
+use consts::{ DEG2RAD };
+use { Transform, Vector3, Quaternion, ApproxEq };
+
+fn right_turn() -> Quaternion {
+    Quaternion::from_angle_axis(90.0 * DEG2RAD, Vector3::UP)
+}
+
+#[test]
+fn constants() {
+    assert_eq!(Transform::IDENTITY, Transform::new(Vector3::ZERO, Quaternion::IDENTITY, Vector3::ONE));
+}
+
+#[test]
+fn transform_point() {
+    let t = Transform::new(Vector3::UP, right_turn(), Vector3::ONE * 2.0);
+
+    assert_approx_eq!(t.transform_point(Vector3::FORWARD), Vector3::new(2.0, 1.0, 0.0));
+    assert_approx_eq!(t * Vector3::FORWARD, Vector3::new(2.0, 1.0, 0.0));
+}
+
+#[test]
+fn transform_direction() {
+    let t = Transform::new(Vector3::UP, right_turn(), Vector3::ONE * 2.0);
+
+    assert_approx_eq!(t.transform_direction(Vector3::FORWARD), Vector3::RIGHT);
+    assert_approx_eq!(t.transform_vector(Vector3::FORWARD), Vector3::RIGHT * 2.0);
+}
+
+#[test]
+fn inverse_transform_point() {
+    let t = Transform::new(Vector3::new(1.0, 2.0, 3.0), right_turn(), Vector3::new(1.0, 2.0, 4.0));
+    let point = Vector3::new(0.5, -1.0, 2.0);
+
+    assert!(Vector3::distance(t.inverse_transform_point(t.transform_point(point)), point) < 1e-5);
+    assert!(Vector3::distance(t.inverse_transform_vector(t.transform_vector(point)), point) < 1e-5);
+    assert!(Vector3::distance(t.inverse_transform_direction(t.transform_direction(point)), point) < 1e-5);
+}
+
+#[test]
+fn inverse() {
+    let t = Transform::new(Vector3::new(1.0, 2.0, 3.0), right_turn(), Vector3::ONE * 2.0);
+    let point = Vector3::new(0.5, -1.0, 2.0);
+
+    assert!(Vector3::distance(t.inverse().transform_point(t.transform_point(point)), point) < 1e-5);
+    let identity = t * t.inverse();
+    assert!(identity.position.magnitude() < 1e-5);
+    assert_approx_eq!(identity.rotation, Quaternion::IDENTITY);
+    assert_approx_eq!(identity.scale, Vector3::ONE);
+}
+
+#[test]
+fn compose() {
+    let parent = Transform::new(Vector3::new(1.0, 2.0, 3.0), right_turn(), Vector3::ONE * 2.0);
+    let child = Transform::new(Vector3::FORWARD, right_turn(), Vector3::ONE);
+    let point = Vector3::new(0.5, -1.0, 2.0);
+
+    let world = parent * child;
+
+    assert!(Vector3::distance(world.transform_point(point), parent.transform_point(child.transform_point(point))) < 1e-5);
+    assert!(Quaternion::angle_between(world.rotation, right_turn() * right_turn()) < 1e-3);
+}
+
+#[test]
+fn non_uniform_scale_is_approximate() {
+    let parent = Transform::new(Vector3::ZERO, Quaternion::IDENTITY, Vector3::new(2.0, 1.0, 1.0));
+    let child = Transform::new(Vector3::ZERO, right_turn(), Vector3::ONE);
+    let point = Vector3::RIGHT;
+
+    // The child turns x onto z where the parent does not stretch, composing stretches x before the turn instead
+    let nested = parent.transform_point(child.transform_point(point));
+    let composed = (parent * child).transform_point(point);
+    assert_approx_eq!(nested.magnitude(), 1.0);
+    assert_approx_eq!(composed.magnitude(), 2.0);
+
+    // Inverting has the same problem, the x stretch lands on z and is never undone
+    let t = Transform::new(Vector3::ZERO, right_turn(), Vector3::new(2.0, 1.0, 1.0));
+    assert_approx_eq!(t.inverse().transform_point(t.transform_point(point)), point * 2.0);
+    assert_approx_eq!(t.inverse_transform_point(t.transform_point(point)), point);
+}
+
+#[test]
+fn lerp() {
+    let a = Transform::IDENTITY;
+    let b = Transform::new(Vector3::RIGHT * 2.0, right_turn(), Vector3::ONE * 3.0);
+
+    let half = Transform::lerp(a, b, 0.5);
+
+    assert_approx_eq!(half.position, Vector3::RIGHT);
+    assert_approx_eq!(half.rotation, Quaternion::from_angle_axis(45.0 * DEG2RAD, Vector3::UP));
+    assert_approx_eq!(half.scale, Vector3::ONE * 2.0);
+    assert_approx_eq!(Transform::lerp(a, b, 1.0), b);
+}
+
+#[test]
+fn to_matrix() {
+    let t = Transform::new(Vector3::new(1.0, 2.0, 3.0), right_turn(), Vector3::new(1.0, 2.0, 4.0));
+    let m = t.to_matrix();
+    let point = Vector3::new(0.5, -1.0, 2.0);
+
+    assert!(Vector3::distance(m.transform_point(point), t.transform_point(point)) < 1e-5);
+
+    let parent = Transform::new(Vector3::UP, right_turn(), Vector3::ONE * 2.0);
+    let composed = (parent * t).to_matrix();
+    let multiplied = parent.to_matrix() * m;
+    assert!(Vector3::distance(composed.transform_point(point), multiplied.transform_point(point)) < 1e-5);
+}
@@ -0,0 +1,114 @@
+use { Vector2, ApproxEq };
+
+#[test]
+fn constructor() {
+    let v = Vector2::new(1.0, 1.0);
+    assert_eq!(v, Vector2::ONE);
+}
+
+#[test]
+fn sqr_magnitude() {
+    let mag = Vector2::new(5.0, 0.0).sqr_magnitude();
+    assert_eq!(mag, 5.0 * 5.0);
+}
+
+#[test]
+fn magnitude() {
+    let mag = Vector2::new(3.0, 4.0).magnitude();
+    assert_eq!(mag, 5.0);
+}
+
+#[test]
+fn normalize_self() {
+    let mut v = Vector2::new(5.0, 0.0);
+    v.normalize();
+
+    assert_eq!(v.x, 1.0);
+}
+
+#[test]
+fn normalized() {
+    let v = Vector2::new(5.0, 0.0).normalized();
+
+    assert_eq!(v.x, 1.0);
+}
+
+#[test]
+fn perpendicular() {
+    assert_eq!(Vector2::RIGHT.perpendicular(), Vector2::UP);
+}
+
+#[test]
+fn dot_product() {
+    assert_eq!(Vector2::dot(Vector2::RIGHT, Vector2::RIGHT), 1.0);
+    assert_eq!(Vector2::dot(Vector2::RIGHT, -Vector2::RIGHT), -1.0);
+    assert_eq!(Vector2::dot(Vector2::RIGHT, Vector2::UP), 0.0);
+}
+
+#[test]
+fn cross_product() {
+    assert_eq!(Vector2::cross(Vector2::RIGHT, Vector2::UP), 1.0);
+    assert_eq!(Vector2::cross(Vector2::UP, Vector2::RIGHT), -1.0);
+}
+
+#[test]
+fn distance() {
+    assert_eq!(Vector2::distance(Vector2::new(3.0, 4.0), Vector2::ZERO), 5.0);
+}
+
+#[test]
+fn angle() {
+    assert_approx_eq!(Vector2::angle(Vector2::RIGHT, Vector2::UP), 90.0_f32.to_radians());
+    assert_approx_eq!(Vector2::signed_angle(Vector2::UP, Vector2::RIGHT), -90.0_f32.to_radians());
+}
+
+#[test]
+fn clamp_magnitude() {
+    let v = Vector2::ONE * 10.0;
+
+    assert_approx_eq!(v.clamp_magnitude(2.0).magnitude(), 2.0);
+}
+
+#[test]
+fn project() {
+    assert_eq!(Vector2::project(Vector2::ONE, Vector2::RIGHT * 2.0), Vector2::RIGHT);
+}
+
+#[test]
+fn reflect() {
+    assert_eq!(Vector2::reflect(Vector2::new(1.0, -1.0), Vector2::UP), Vector2::new(1.0, 1.0));
+}
+
+#[test]
+fn lerp() {
+    assert_eq!(Vector2::lerp(Vector2::ZERO, Vector2::RIGHT, 0.5), Vector2::new(0.5, 0.0));
+    assert_eq!(Vector2::lerp(Vector2::ZERO, Vector2::RIGHT, 2.0), Vector2::RIGHT);
+    assert_eq!(Vector2::lerp_unclamped(Vector2::ZERO, Vector2::RIGHT, 2.0), Vector2::new(2.0, 0.0));
+}
+
+// Operators
+#[test]
+fn add_vector() {
+    assert_eq!(Vector2::RIGHT + Vector2::UP, Vector2::ONE);
+}
+
+#[test]
+fn sub_vector() {
+    assert_eq!(Vector2::ONE - Vector2::UP, Vector2::RIGHT);
+}
+
+#[test]
+fn mul_scalar() {
+    assert_eq!(Vector2::ONE * 2.0, Vector2::new(2.0, 2.0));
+    assert_eq!(2.0 * Vector2::ONE, Vector2::new(2.0, 2.0));
+}
+
+#[test]
+fn div_scalar() {
+    assert_eq!(Vector2::new(2.0, 2.0) / 2.0, Vector2::ONE);
+}
+
+#[test]
+fn neg_vector() {
+    assert_eq!(-Vector2::ONE, Vector2::new(-1.0, -1.0));
+}
@@ -1,4 +1,4 @@
-use { Vector3, ApproxEq };
+use { Clamp, MathError, Vector3, Vector4, ApproxEq };
 
 #[test]
 fn constructor() {
@@ -6,6 +6,11 @@ fn constructor() {
     assert_eq!(v, Vector3::ONE);
 }
 
+#[test]
+fn extend() {
+    assert_eq!(Vector3::new(1.0, 2.0, 3.0).extend(4.0), Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
 #[test]
 fn sqr_magnitude() {
     let mag = Vector3::new(5.0, 0.0, 0.0).sqr_magnitude();
@@ -33,6 +38,28 @@ fn normalized() {
     assert_eq!(v.x, 1.0);
 }
 
+#[test]
+fn try_normalized() {
+    assert_eq!(Vector3::new(0.0, 3.0, 4.0).try_normalized(), Ok(Vector3::new(0.0, 0.6, 0.8)));
+    assert_eq!(Vector3::ZERO.try_normalized(), Err(MathError::ZeroLength));
+    assert_eq!(Vector3::new(f32::NAN, 0.0, 0.0).try_normalized(), Err(MathError::NonFinite));
+    assert_eq!(Vector3::new(f32::INFINITY, 0.0, 0.0).try_normalized(), Err(MathError::NonFinite));
+
+    // The length overflows but the components are finite
+    assert_approx_eq!(Vector3::new(1e20, 1e20, 1e20).try_normalized().unwrap(), Vector3::ONE.normalized());
+    assert_approx_eq!(Vector3::new(f32::MAX, 0.0, f32::MAX).try_normalized().unwrap().magnitude(), 1.0);
+}
+
+#[test]
+fn normalize_or() {
+    assert_eq!(Vector3::ZERO.normalized_or(Vector3::UP), Vector3::UP);
+    assert_eq!(Vector3::new(2.0, 0.0, 0.0).normalized_or(Vector3::UP), Vector3::RIGHT);
+
+    let mut v = Vector3::new(f32::NAN, 0.0, 0.0);
+    v.normalize_or(Vector3::FORWARD);
+    assert_eq!(v, Vector3::FORWARD);
+}
+
 #[test]
 fn dot_product() {
     let left = Vector3::new(-1.0, 0.0, 0.0);
@@ -75,7 +102,8 @@ fn angle() {
 
     let angle = Vector3::angle(v0, v1);
 
-    assert_eq!(angle, 90.0_f32.to_radians());
+    // acos differs in the last bit between the std and libm backends
+    assert!((angle - 90.0_f32.to_radians()).abs() < 1e-6);
 }
 
 #[test]
@@ -221,4 +249,143 @@ fn neg_vector() {
     assert_eq!(neg_v.x, -1.0);
     assert_eq!(neg_v.y, -1.0);
     assert_eq!(neg_v.z, -1.0);
-}
\ No newline at end of file
+}
+
+#[test]
+fn move_towards() {
+    let a = Vector3::ZERO;
+    let b = Vector3::RIGHT * 4.0;
+
+    assert_eq!(Vector3::move_towards(a, b, 1.0), Vector3::RIGHT);
+    assert_eq!(Vector3::move_towards(a, b, 10.0), b);
+}
+
+#[test]
+fn rotate_towards() {
+    let a = Vector3::FORWARD;
+    let b = Vector3::RIGHT * 3.0;
+
+    let half = Vector3::rotate_towards(a, b, 45.0_f32.to_radians(), 1.0);
+    assert_approx_eq!(Vector3::angle(half, a), 45.0_f32.to_radians());
+    assert_approx_eq!(half.magnitude(), 2.0);
+
+    assert_eq!(Vector3::rotate_towards(a, b, 180.0_f32.to_radians(), 10.0), b);
+
+    let opposite = Vector3::rotate_towards(a, -a, 90.0_f32.to_radians(), 0.0);
+    assert_approx_eq!(Vector3::angle(opposite, a), 90.0_f32.to_radians());
+}
+
+#[test]
+fn min_max() {
+    let a = Vector3::new(1.0, -2.0, 3.0);
+    let b = Vector3::new(-1.0, 2.0, -3.0);
+
+    assert_eq!(Vector3::min(a, b), Vector3::new(-1.0, -2.0, -3.0));
+    assert_eq!(Vector3::max(a, b), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn abs() {
+    assert_eq!(Vector3::new(1.0, -2.0, 3.0).abs(), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn floor_ceil_round() {
+    let v = Vector3::new(1.25, -1.75, 2.5);
+
+    assert_eq!(v.floor(), Vector3::new(1.0, -2.0, 2.0));
+    assert_eq!(v.ceil(), Vector3::new(2.0, -1.0, 3.0));
+    assert_eq!(v.round(), Vector3::new(1.0, -2.0, 3.0));
+}
+
+#[test]
+fn signum() {
+    assert_eq!(Vector3::new(2.0, -3.0, 0.5).signum(), Vector3::new(1.0, -1.0, 1.0));
+}
+
+#[test]
+fn clamp() {
+    let v = Vector3::new(-5.0, 0.5, 5.0);
+
+    assert_eq!(v.clamp(Vector3::ZERO, Vector3::ONE), Vector3::new(0.0, 0.5, 1.0));
+}
+
+#[test]
+fn recip() {
+    assert_eq!(Vector3::new(2.0, 4.0, 0.5).recip(), Vector3::new(0.5, 0.25, 2.0));
+}
+
+#[test]
+fn min_max_element() {
+    let v = Vector3::new(3.0, -2.0, 5.0);
+
+    assert_eq!(v.min_element(), -2.0);
+    assert_eq!(v.max_element(), 5.0);
+}
+
+#[test]
+fn mul_add() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+
+    assert_eq!(v.mul_add(Vector3::ONE * 2.0, Vector3::ONE), Vector3::new(3.0, 5.0, 7.0));
+}
+
+#[test]
+fn is_finite_is_nan() {
+    assert!(Vector3::ONE.is_finite());
+    assert!(!(Vector3::ONE * f32::INFINITY).is_finite());
+    assert!(!Vector3::ONE.is_nan());
+    assert!((Vector3::ONE * f32::NAN).is_nan());
+}
+
+#[test]
+fn div_vector() {
+    assert_eq!(Vector3::new(2.0, 6.0, 12.0) / Vector3::new(1.0, 2.0, 3.0), Vector3::new(2.0, 3.0, 4.0));
+}
+
+#[test]
+fn index() {
+    let mut v = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(v[0], 1.0);
+    assert_eq!(v[1], 2.0);
+    assert_eq!(v[2], 3.0);
+
+    v[1] = 5.0;
+    assert_eq!(v, Vector3::new(1.0, 5.0, 3.0));
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_range() {
+    let v = Vector3::ONE;
+    let _ = v[3];
+}
+
+#[test]
+fn array_conversions() {
+    let v = Vector3::from([1.0, 2.0, 3.0]);
+    assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+
+    let a: [f32; 3] = v.into();
+    assert_eq!(a, [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn tuple_conversions() {
+    let v = Vector3::from((1.0, 2.0, 3.0));
+    assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+
+    let t: (f32, f32, f32) = v.into();
+    assert_eq!(t, (1.0, 2.0, 3.0));
+}
+
+#[test]
+fn slice_conversions() {
+    let mut v = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.as_ref(), &[1.0, 2.0, 3.0]);
+
+    v.as_mut()[2] = 4.0;
+    assert_eq!(v.z, 4.0);
+
+    assert_eq!(Vector3::from_slice(&[1.0, 2.0, 3.0, 4.0]), Vector3::new(1.0, 2.0, 3.0));
+}
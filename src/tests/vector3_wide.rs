@@ -0,0 +1,148 @@
+use { ApproxEq, Mask4, Mask8, Vector3, Vector3x4, Vector3x8 };
+
+fn points() -> [Vector3; 8] {
+    [
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(-4.0, 0.5, 2.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(3.0, -1.0, 7.5),
+        Vector3::new(-2.0, -2.0, -2.0),
+        Vector3::new(10.0, 0.0, -1.0),
+        Vector3::new(0.25, 0.5, 0.75),
+        Vector3::new(6.0, 5.0, 4.0)
+    ]
+}
+
+// Vector3 equality is approximate, lanes have to match the scalar operations exactly
+fn bits(v: Vector3) -> [u32; 3] {
+    [v.x.to_bits(), v.y.to_bits(), v.z.to_bits()]
+}
+
+#[test]
+fn pack_and_unpack() {
+    let points = points();
+    let wide = Vector3x8::from_slice(&points);
+
+    let mut out = [Vector3::ZERO; 8];
+    wide.write_to_slice(&mut out);
+
+    for i in 0..8 {
+        assert_eq!(out[i], points[i]);
+        assert_eq!(wide.lane(i), points[i]);
+    }
+}
+
+#[test]
+fn pack_partial() {
+    let points = points();
+    let wide = Vector3x4::from_slice_or(&points[..2], Vector3::ONE);
+
+    assert_eq!(wide.lane(0), points[0]);
+    assert_eq!(wide.lane(1), points[1]);
+    assert_eq!(wide.lane(2), Vector3::ONE);
+    assert_eq!(wide.lane(3), Vector3::ONE);
+}
+
+#[test]
+#[should_panic]
+fn pack_short_slice() {
+    Vector3x4::from_slice(&points()[..3]);
+}
+
+#[test]
+fn lanes_match_vector3() {
+    let points = points();
+    let a = Vector3x4::from_slice(&points[..4]);
+    let b = Vector3x4::from_slice(&points[4..]);
+
+    let dot = Vector3x4::dot(a, b);
+    let cross = Vector3x4::cross(a, b);
+    let normalized = a.normalized();
+    let magnitude = a.magnitude();
+    let sum = a + b;
+    let difference = a - b;
+    let scaled = a * 2.5;
+    let lerped = Vector3x4::lerp_unclamped(a, b, 0.3);
+
+    for i in 0..4 {
+        let (pa, pb) = (points[i], points[i + 4]);
+
+        assert_eq!(dot[i].to_bits(), Vector3::dot(pa, pb).to_bits());
+        assert_eq!(bits(cross.lane(i)), bits(Vector3::cross(pa, pb)));
+        assert_eq!(bits(normalized.lane(i)), bits(pa.normalized()));
+        assert_eq!(magnitude[i].to_bits(), pa.magnitude().to_bits());
+        assert_eq!(bits(sum.lane(i)), bits(pa + pb));
+        assert_eq!(bits(difference.lane(i)), bits(pa - pb));
+        assert_eq!(bits(scaled.lane(i)), bits(pa * 2.5));
+        assert_eq!(bits(lerped.lane(i)), bits(Vector3::lerp_unclamped(pa, pb, 0.3)));
+    }
+}
+
+#[test]
+fn zero_lanes_normalize_to_zero() {
+    let wide = Vector3x4::from_slice(&points()[..4]).normalized();
+
+    assert_eq!(wide.lane(2), Vector3::ZERO);
+    assert!(wide.lane(0).magnitude().approx_eq(1.0));
+}
+
+#[test]
+fn select() {
+    let a = Vector3x4::splat(Vector3::ONE);
+    let b = Vector3x4::ZERO;
+    let mask = Mask4::new([true, false, false, true]);
+
+    let selected = Vector3x4::select(mask, a, b);
+    assert_eq!(selected.lane(0), Vector3::ONE);
+    assert_eq!(selected.lane(1), Vector3::ZERO);
+    assert_eq!(selected.lane(3), Vector3::ONE);
+}
+
+#[test]
+fn masked_culling() {
+    // Keep the points in front of a plane facing +x through the origin
+    let points = Vector3x8::from_slice(&points());
+    let normal = Vector3x8::splat(Vector3::RIGHT);
+
+    let front = Mask8::gt(Vector3x8::dot(points, normal), [0.0; 8]);
+    assert_eq!(front.bitmask(), 0b1110_1001);
+
+    let culled = Vector3x8::select(front, points, Vector3x8::ZERO);
+    assert_eq!(culled.lane(1), Vector3::ZERO);
+    assert_eq!(culled.lane(7), Vector3::new(6.0, 5.0, 4.0));
+}
+
+#[test]
+fn horizontal_reductions() {
+    let points = points();
+    let wide = Vector3x8::from_slice(&points);
+
+    let mut sum = Vector3::ZERO;
+    for p in points.iter() {
+        sum = sum + *p;
+    }
+
+    assert_eq!(wide.horizontal_sum(), sum);
+    assert_eq!(wide.horizontal_min(), Vector3::new(-4.0, -2.0, -2.0));
+    assert_eq!(wide.horizontal_max(), Vector3::new(10.0, 5.0, 7.5));
+}
+
+#[test]
+fn per_lane_scale() {
+    let wide = Vector3x4::splat(Vector3::ONE) * [1.0, 2.0, 3.0, 4.0];
+
+    assert_eq!(wide.lane(3), Vector3::new(4.0, 4.0, 4.0));
+    assert_eq!((wide / [1.0, 2.0, 3.0, 4.0]).lane(3), Vector3::ONE);
+}
+
+#[test]
+fn approx_eq() {
+    let a = Vector3x4::splat(Vector3::new(0.1, 0.2, 0.3));
+    let mut b = Vector3x4::splat(Vector3::new(0.3, 0.2, 0.1) * 0.5 + Vector3::new(-0.05, 0.1, 0.25));
+    assert!(a.approx_eq(b));
+    assert_eq!(a, b);
+
+    b.x[2] = 0.2;
+    assert!(!a.approx_eq(b));
+    assert!(a != b);
+}
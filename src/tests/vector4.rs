@@ -1,4 +1,4 @@
-use { Vector4 , ApproxEq };
+use { Clamp, MathError, Vector3, Vector4 , ApproxEq };
 
 #[test]
 fn constructor() {
@@ -6,6 +6,27 @@ fn constructor() {
     assert_eq!(v, Vector4::ONE);
 }
 
+#[test]
+fn from_point() {
+    assert_eq!(Vector4::from_point(Vector3::new(1.0, 2.0, 3.0)), Vector4::new(1.0, 2.0, 3.0, 1.0));
+}
+
+#[test]
+fn from_direction() {
+    assert_eq!(Vector4::from_direction(Vector3::new(1.0, 2.0, 3.0)), Vector4::new(1.0, 2.0, 3.0, 0.0));
+}
+
+#[test]
+fn truncate() {
+    assert_eq!(Vector4::new(1.0, 2.0, 3.0, 4.0).truncate(), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn perspective_divide() {
+    assert_eq!(Vector4::new(2.0, 4.0, 6.0, 2.0).perspective_divide(), Some(Vector3::new(1.0, 2.0, 3.0)));
+    assert_eq!(Vector4::new(2.0, 4.0, 6.0, 0.0).perspective_divide(), None);
+}
+
 #[test]
 fn sqr_magnitude() {
     let mag = Vector4::new(5.0, 0.0, 0.0, 0.0).sqr_magnitude();
@@ -33,6 +54,27 @@ fn normalized() {
     assert_eq!(v.x, 1.0);
 }
 
+#[test]
+fn try_normalized() {
+    assert_eq!(Vector4::new(0.0, 0.0, 3.0, 4.0).try_normalized(), Ok(Vector4::new(0.0, 0.0, 0.6, 0.8)));
+    assert_eq!(Vector4::ZERO.try_normalized(), Err(MathError::ZeroLength));
+    assert_eq!(Vector4::new(0.0, 0.0, 0.0, f32::NAN).try_normalized(), Err(MathError::NonFinite));
+
+    // The length overflows but the components are finite
+    assert_approx_eq!(Vector4::new(0.0, 0.0, 3e20, 4e20).try_normalized().unwrap(), Vector4::new(0.0, 0.0, 0.6, 0.8));
+}
+
+#[test]
+fn normalize_or() {
+    let fallback = Vector4::new(0.0, 0.0, 0.0, 1.0);
+
+    assert_eq!(Vector4::ZERO.normalized_or(fallback), fallback);
+
+    let mut v = Vector4::new(2.0, 0.0, 0.0, 0.0);
+    v.normalize_or(fallback);
+    assert_eq!(v, Vector4::new(1.0, 0.0, 0.0, 0.0));
+}
+
 #[test]
 fn dot_product() {
     let left = Vector4::new(-1.0, 0.0, 0.0, 0.0);
@@ -175,4 +217,95 @@ fn neg_vector() {
     assert_eq!(neg_v.y, -1.0);
     assert_eq!(neg_v.z, -1.0);
     assert_eq!(neg_v.w, -1.0);
-}
\ No newline at end of file
+}
+
+#[test]
+fn min_max() {
+    let a = Vector4::new(1.0, -2.0, 3.0, -4.0);
+    let b = Vector4::new(-1.0, 2.0, -3.0, 4.0);
+
+    assert_eq!(Vector4::min(a, b), Vector4::new(-1.0, -2.0, -3.0, -4.0));
+    assert_eq!(Vector4::max(a, b), Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn abs() {
+    assert_eq!(Vector4::new(1.0, -2.0, 3.0, -4.0).abs(), Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn floor_ceil_round() {
+    let v = Vector4::new(1.25, -1.75, 2.5, -0.5);
+
+    assert_eq!(v.floor(), Vector4::new(1.0, -2.0, 2.0, -1.0));
+    assert_eq!(v.ceil(), Vector4::new(2.0, -1.0, 3.0, 0.0));
+    assert_eq!(v.round(), Vector4::new(1.0, -2.0, 3.0, -1.0));
+}
+
+#[test]
+fn signum() {
+    assert_eq!(Vector4::new(2.0, -3.0, 0.5, -0.25).signum(), Vector4::new(1.0, -1.0, 1.0, -1.0));
+}
+
+#[test]
+fn clamp() {
+    let v = Vector4::new(-5.0, 0.5, 5.0, 2.0);
+
+    assert_eq!(v.clamp(Vector4::ZERO, Vector4::ONE), Vector4::new(0.0, 0.5, 1.0, 1.0));
+}
+
+#[test]
+fn recip() {
+    assert_eq!(Vector4::new(2.0, 4.0, 0.5, 0.25).recip(), Vector4::new(0.5, 0.25, 2.0, 4.0));
+}
+
+#[test]
+fn min_max_element() {
+    let v = Vector4::new(3.0, -2.0, 5.0, 1.0);
+
+    assert_eq!(v.min_element(), -2.0);
+    assert_eq!(v.max_element(), 5.0);
+}
+
+#[test]
+fn mul_add() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_eq!(v.mul_add(Vector4::ONE * 2.0, Vector4::ONE), Vector4::new(3.0, 5.0, 7.0, 9.0));
+}
+
+#[test]
+fn is_finite_is_nan() {
+    assert!(Vector4::ONE.is_finite());
+    assert!(!(Vector4::ONE * f32::INFINITY).is_finite());
+    assert!(!Vector4::ONE.is_nan());
+    assert!((Vector4::ONE * f32::NAN).is_nan());
+}
+
+#[test]
+fn div_vector() {
+    assert_eq!(Vector4::new(2.0, 6.0, 12.0, 20.0) / Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::new(2.0, 3.0, 4.0, 5.0));
+}
+
+#[test]
+fn index() {
+    let mut v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v[3], 4.0);
+
+    v[0] = 5.0;
+    assert_eq!(v, Vector4::new(5.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn conversions() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(Vector4::from([1.0, 2.0, 3.0, 4.0]), v);
+    assert_eq!(Vector4::from((1.0, 2.0, 3.0, 4.0)), v);
+    assert_eq!(Vector4::from_slice(&[1.0, 2.0, 3.0, 4.0]), v);
+
+    let a: [f32; 4] = v.into();
+    let t: (f32, f32, f32, f32) = v.into();
+    assert_eq!(a, [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(t, (1.0, 2.0, 3.0, 4.0));
+    assert_eq!(v.as_ref(), &[1.0, 2.0, 3.0, 4.0]);
+}
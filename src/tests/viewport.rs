@@ -0,0 +1,119 @@
+use consts::{ DEG2RAD };
+use { Viewport, DepthRange, ViewportOrigin, Matrix4x4, Vector2, Vector3, Vector4, ApproxEq };
+
+// Left handed perspective looking down +z
+fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32, depth_range: DepthRange) -> Matrix4x4 {
+    let f = 1.0 / (fov_y * 0.5).tan();
+    let (a, b) = match depth_range {
+        DepthRange::ZeroToOne => (far / (far - near), -near * far / (far - near)),
+        DepthRange::NegativeOneToOne => ((far + near) / (far - near), -2.0 * near * far / (far - near))
+    };
+
+    Matrix4x4::new(
+        Vector4::new(f / aspect, 0.0, 0.0, 0.0),
+        Vector4::new(0.0, f, 0.0, 0.0),
+        Vector4::new(0.0, 0.0, a, 1.0),
+        Vector4::new(0.0, 0.0, b, 0.0)
+    )
+}
+
+fn close(a: Vector3, b: Vector3) -> bool {
+    Vector3::distance(a, b) < 1e-3
+}
+
+#[test]
+fn project_center() {
+    let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    let vp = perspective(90.0 * DEG2RAD, 800.0 / 600.0, 1.0, 100.0, DepthRange::ZeroToOne);
+
+    let near = viewport.project(Vector3::FORWARD, vp).unwrap();
+    assert_approx_eq!(Vector2::new(near.x, near.y), Vector2::new(400.0, 300.0));
+    assert_approx_eq!(near.z, 0.0);
+
+    let far = viewport.project(Vector3::FORWARD * 100.0, vp).unwrap();
+    assert_approx_eq!(far.z, 1.0);
+}
+
+#[test]
+fn project_behind_camera() {
+    let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    let vp = perspective(90.0 * DEG2RAD, 1.0, 1.0, 100.0, DepthRange::ZeroToOne);
+
+    assert!(viewport.project(-Vector3::FORWARD, vp).is_none());
+    assert!(viewport.project(Vector3::ZERO, vp).is_none());
+}
+
+#[test]
+fn project_origin() {
+    let vp = perspective(90.0 * DEG2RAD, 1.0, 1.0, 100.0, DepthRange::ZeroToOne);
+    let point = Vector3::new(1.0, 1.0, 1.0);
+
+    let top_left = Viewport::new(0.0, 0.0, 100.0, 100.0);
+    let projected = top_left.project(point, vp).unwrap();
+    assert_approx_eq!(Vector2::new(projected.x, projected.y), Vector2::new(100.0, 0.0));
+
+    let mut bottom_left = Viewport::new(0.0, 0.0, 100.0, 100.0);
+    bottom_left.origin = ViewportOrigin::BottomLeft;
+    let projected = bottom_left.project(point, vp).unwrap();
+    assert_approx_eq!(Vector2::new(projected.x, projected.y), Vector2::new(100.0, 100.0));
+}
+
+#[test]
+fn project_offset_viewport() {
+    let viewport = Viewport::new(100.0, 50.0, 200.0, 200.0);
+    let vp = perspective(90.0 * DEG2RAD, 1.0, 1.0, 100.0, DepthRange::ZeroToOne);
+
+    let projected = viewport.project(Vector3::FORWARD * 10.0, vp).unwrap();
+    assert_approx_eq!(Vector2::new(projected.x, projected.y), Vector2::new(200.0, 150.0));
+}
+
+#[test]
+fn unproject_round_trip() {
+    let point = Vector3::new(3.0, -2.0, 20.0);
+
+    for depth_range in &[DepthRange::ZeroToOne, DepthRange::NegativeOneToOne] {
+        for origin in &[ViewportOrigin::TopLeft, ViewportOrigin::BottomLeft] {
+            let mut viewport = Viewport::new(10.0, 20.0, 640.0, 480.0);
+            viewport.depth_range = *depth_range;
+            viewport.origin = *origin;
+
+            let vp = perspective(60.0 * DEG2RAD, 640.0 / 480.0, 0.5, 200.0, *depth_range);
+
+            let screen = viewport.project(point, vp).unwrap();
+            let world = viewport.unproject(screen, vp).unwrap();
+
+            assert!(close(world, point));
+        }
+    }
+}
+
+#[test]
+fn screen_point_to_ray() {
+    let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    let vp = perspective(90.0 * DEG2RAD, 800.0 / 600.0, 1.0, 100.0, DepthRange::ZeroToOne);
+
+    let center = viewport.screen_point_to_ray(Vector2::new(400.0, 300.0), vp).unwrap();
+    assert!(close(center.origin, Vector3::FORWARD));
+    assert!(close(center.direction, Vector3::FORWARD));
+
+    let point = Vector3::new(4.0, 2.0, 30.0);
+    let screen = viewport.project(point, vp).unwrap();
+    let ray = viewport.screen_point_to_ray(Vector2::new(screen.x, screen.y), vp).unwrap();
+
+    let to_point = (point - ray.origin).normalized();
+    assert!(close(ray.direction, to_point));
+}
+
+#[test]
+fn unproject_singular() {
+    let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    let vp = perspective(90.0 * DEG2RAD, 800.0 / 600.0, 1.0, 100.0, DepthRange::ZeroToOne);
+
+    assert_eq!(viewport.unproject(Vector3::new(400.0, 300.0, 0.5), Matrix4x4::ZERO), None);
+    assert!(viewport.screen_point_to_ray(Vector2::new(400.0, 300.0), Matrix4x4::ZERO).is_none());
+
+    // A zero sized viewport divides by zero on the way back to device coordinates
+    let empty = Viewport::new(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(empty.unproject(Vector3::new(0.0, 0.0, 0.5), vp), None);
+    assert!(empty.screen_point_to_ray(Vector2::ZERO, vp).is_none());
+}
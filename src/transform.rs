@@ -0,0 +1,172 @@
+use core::ops::{ Mul, MulAssign };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
+
+use { ApproxEq, Clamp01, Vector3, Quaternion, Matrix4x4 };
+
+// Scale, then rotation, then translation. Under a non-uniform scale a child rotation turns into shear, which
+// this type cannot hold, so composing and inverting are only exact while the scale involved is uniform. Use
+// to_matrix or Affine3 when that matters
+#[derive(Clone, Copy)]
+pub struct Transform {
+    pub position: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3
+}
+
+#[allow(dead_code)]
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        position: Vector3::ZERO,
+        rotation: Quaternion::IDENTITY,
+        scale: Vector3::ONE
+    };
+
+    pub fn new(position: Vector3, rotation: Quaternion, scale: Vector3) -> Transform {
+        Transform {
+            position,
+            rotation,
+            scale
+        }
+    }
+
+    pub fn from_position(position: Vector3) -> Transform {
+        Transform::new(position, Quaternion::IDENTITY, Vector3::ONE)
+    }
+
+    pub fn from_rotation(rotation: Quaternion) -> Transform {
+        Transform::new(Vector3::ZERO, rotation, Vector3::ONE)
+    }
+
+    pub fn from_scale(scale: Vector3) -> Transform {
+        Transform::new(Vector3::ZERO, Quaternion::IDENTITY, scale)
+    }
+
+    pub fn forward(&self) -> Vector3 {
+        self.rotation.forward()
+    }
+
+    pub fn right(&self) -> Vector3 {
+        self.rotation.right()
+    }
+
+    pub fn up(&self) -> Vector3 {
+        self.rotation.up()
+    }
+
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        self.position + self.rotation * Vector3::scale(point, self.scale)
+    }
+
+    pub fn transform_vector(&self, vector: Vector3) -> Vector3 {
+        self.rotation * Vector3::scale(vector, self.scale)
+    }
+
+    // Directions are only rotated, scale does not affect them
+    pub fn transform_direction(&self, direction: Vector3) -> Vector3 {
+        self.rotation * direction
+    }
+
+    pub fn inverse_transform_point(&self, point: Vector3) -> Vector3 {
+        let local = self.rotation.inverse() * (point - self.position);
+
+        Vector3::scale(local, Transform::inverse_scale(self.scale))
+    }
+
+    pub fn inverse_transform_vector(&self, vector: Vector3) -> Vector3 {
+        let local = self.rotation.inverse() * vector;
+
+        Vector3::scale(local, Transform::inverse_scale(self.scale))
+    }
+
+    pub fn inverse_transform_direction(&self, direction: Vector3) -> Vector3 {
+        self.rotation.inverse() * direction
+    }
+
+    // Exact for uniform scale or no rotation. Otherwise the inverse would have to scale before rotating, which a
+    // single transform cannot represent, the inverse_transform_ functions stay exact
+    pub fn inverse(&self) -> Transform {
+        let rotation = self.rotation.inverse();
+        let scale = Transform::inverse_scale(self.scale);
+
+        Transform {
+            position: Vector3::scale(rotation * -self.position, scale),
+            rotation,
+            scale
+        }
+    }
+
+    pub fn lerp(from: Transform, to: Transform, t: f32) -> Transform {
+        let t = t.clamp01();
+
+        Transform {
+            position: Vector3::lerp_unclamped(from.position, to.position, t),
+            rotation: Quaternion::slerp_unclamped(from.rotation, to.rotation, t),
+            scale: Vector3::lerp_unclamped(from.scale, to.scale, t)
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix4x4 {
+        Matrix4x4::make_from_trs(self.position, self.rotation, self.scale)
+    }
+
+    fn inverse_scale(scale: Vector3) -> Vector3 {
+        Vector3 {
+            x: if scale.x != 0.0 { 1.0 / scale.x } else { 0.0 },
+            y: if scale.y != 0.0 { 1.0 / scale.y } else { 0.0 },
+            z: if scale.z != 0.0 { 1.0 / scale.z } else { 0.0 }
+        }
+    }
+}
+
+impl fmt::Debug for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(position: {:?}, rotation: {:?}, scale: {:?})", self.position, self.rotation, self.scale)
+    }
+}
+
+impl fmt::Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(position: {}, rotation: {}, scale: {})", self.position, self.rotation, self.scale)
+    }
+}
+
+impl PartialEq for Transform {
+    fn eq(&self, other: &Transform) -> bool {
+        self.approx_eq(*other)
+    }
+}
+
+impl Eq for Transform {}
+
+impl_op! { ApproxEq,
+    fn approx_eq(self: Transform, other: Transform) -> bool {
+        self.position.approx_eq(other.position) &&
+        self.rotation.approx_eq(other.rotation) &&
+        self.scale.approx_eq(other.scale)
+    }
+}
+
+// Parent * child, the result maps from the child's local space into the parent's parent space. Scales multiply
+// per axis, which is only exact when the parent scale is uniform or the child is not rotated
+impl_op! { Mul,
+    fn mul(self: Transform, other: Transform) -> Transform {
+        Transform {
+            position: self.transform_point(other.position),
+            rotation: self.rotation * other.rotation,
+            scale: Vector3::scale(self.scale, other.scale)
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Transform, other: Vector3) -> Vector3 {
+        self.transform_point(other)
+    }
+}
+
+impl_op! { MulAssign,
+    fn mul_assign(&mut self: Transform, other: Transform) {
+        *self = *self * other;
+    }
+}
@@ -0,0 +1,270 @@
+use core::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
+
+use {ApproxEq, Clamp01};
+use consts::{ EPSILON };
+use math;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32
+}
+
+impl Vector2 {
+    pub const ZERO: Vector2 = Vector2 { x: 0.0, y: 0.0 };
+    pub const ONE: Vector2 = Vector2 { x: 1.0, y: 1.0 };
+    pub const RIGHT: Vector2 = Vector2 { x: 1.0, y: 0.0 };
+    pub const UP: Vector2 = Vector2 { x: 0.0, y: 1.0 };
+
+    pub fn new(x: f32, y: f32) -> Vector2 {
+        Vector2 {
+            x,
+            y
+        }
+    }
+
+    pub fn clamp_magnitude(&self, max_length: f32) -> Vector2 {
+        if self.sqr_magnitude() > max_length * max_length {
+            return self.normalized() * max_length
+        }
+
+        *self
+    }
+
+    pub fn sqr_magnitude(&self) -> f32 {
+        self.x * self.x + self.y * self.y
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        math::sqrt(self.x * self.x + self.y * self.y)
+    }
+
+    pub fn normalize(&mut self) {
+        let mag = self.magnitude();
+        if mag > EPSILON {
+            *self /= mag;
+        }
+        else {
+            *self = Vector2::ZERO;
+        }
+    }
+
+    pub fn normalized(&self) -> Vector2 {
+        let mag = self.magnitude();
+        if mag > EPSILON {
+            return *self / mag;
+        }
+
+        Vector2::ZERO
+    }
+
+    // Counter-clockwise perpendicular
+    pub fn perpendicular(&self) -> Vector2 {
+        Vector2 {
+            x: -self.y,
+            y: self.x
+        }
+    }
+
+    pub fn dot(a: Vector2, b: Vector2) -> f32 {
+        a.x * b.x + a.y * b.y
+    }
+
+    // Z component of the 3D cross product, positive when b is counter-clockwise from a
+    pub fn cross(a: Vector2, b: Vector2) -> f32 {
+        a.x * b.y - a.y * b.x
+    }
+
+    pub fn distance(a: Vector2, b: Vector2) -> f32 {
+        (a - b).magnitude()
+    }
+
+    pub fn angle(a: Vector2, b: Vector2) -> f32 {
+        math::acos(Vector2::dot(a.normalized(), b.normalized()).clamp(-1.0, 1.0))
+    }
+
+    pub fn signed_angle(a: Vector2, b: Vector2) -> f32 {
+        math::atan2(Vector2::cross(a, b), Vector2::dot(a, b))
+    }
+
+    pub fn scale(v: Vector2, other: Vector2) -> Vector2 {
+        Vector2 {
+            x: v.x * other.x,
+            y: v.y * other.y
+        }
+    }
+
+    pub fn lerp(start: Vector2, end: Vector2, t: f32) -> Vector2 {
+        let alpha = t.clamp01();
+
+        Vector2 {
+            x: start.x + (end.x - start.x) * alpha,
+            y: start.y + (end.y - start.y) * alpha
+        }
+    }
+
+    pub fn lerp_unclamped(start: Vector2, end: Vector2, t: f32) -> Vector2 {
+        Vector2 {
+            x: start.x + (end.x - start.x) * t,
+            y: start.y + (end.y - start.y) * t
+        }
+    }
+
+    pub fn project(v: Vector2, normal: Vector2) -> Vector2 {
+        let dot = Vector2::dot(normal, normal);
+        if dot < EPSILON {
+            Vector2::ZERO
+        }
+        else {
+            normal * Vector2::dot(v, normal) / dot
+        }
+    }
+
+    pub fn reflect(v: Vector2, normal: Vector2) -> Vector2 {
+        -2.0 * Vector2::dot(normal, v) * normal + v
+    }
+}
+
+// Conversions
+impl_array_conversions!(Vector2, 2, (f32, f32), x: 0, y: 1);
+
+// Formatting
+impl fmt::Debug for Vector2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Vector2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// Equality
+impl PartialEq for Vector2 {
+    fn eq(&self, other: &Vector2) -> bool {
+        self.x.approx_eq(other.x) && self.y.approx_eq(other.y)
+    }
+}
+
+impl Eq for Vector2 {}
+
+impl_op! { ApproxEq,
+    fn approx_eq(self: Vector2, other: Vector2) -> bool {
+        self.x.approx_eq(other.x) && self.y.approx_eq(other.y)
+    }
+}
+
+// Ops
+impl_op! { Add,
+    fn add(self: Vector2, other: Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x + other.x,
+            y: self.y + other.y
+        }
+    }
+}
+
+impl_op! { Add,
+    fn add(self: Vector2, other: f32) -> Vector2 {
+        Vector2 {
+            x: self.x + other,
+            y: self.y + other
+        }
+    }
+}
+
+impl_op! { Sub,
+    fn sub(self: Vector2, other: Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x - other.x,
+            y: self.y - other.y
+        }
+    }
+}
+
+impl_op! { Sub,
+    fn sub(self: Vector2, other: f32) -> Vector2 {
+        Vector2 {
+            x: self.x - other,
+            y: self.y - other
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Vector2, other: Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x * other.x,
+            y: self.y * other.y
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: Vector2, other: f32) -> Vector2 {
+        Vector2 {
+            x: self.x * other,
+            y: self.y * other
+        }
+    }
+}
+
+impl_op! { Mul,
+    fn mul(self: f32, other: Vector2) -> Vector2 {
+        Vector2 {
+            x: other.x * self,
+            y: other.y * self
+        }
+    }
+}
+
+impl_op! { Div,
+    fn div(self: Vector2, other: f32) -> Vector2 {
+        Vector2 {
+            x: self.x / other,
+            y: self.y / other
+        }
+    }
+}
+
+impl_op! { Neg,
+    fn neg(self: Vector2) -> Vector2 {
+        Vector2 {
+            x: -self.x,
+            y: -self.y
+        }
+    }
+}
+
+impl_op! { AddAssign,
+    fn add_assign(&mut self: Vector2, other: f32) {
+        self.x += other;
+        self.y += other;
+    }
+}
+
+impl_op! { SubAssign,
+    fn sub_assign(&mut self: Vector2, other: f32) {
+        self.x -= other;
+        self.y -= other;
+    }
+}
+
+impl_op! { MulAssign,
+    fn mul_assign(&mut self: Vector2, other: f32) {
+        self.x *= other;
+        self.y *= other;
+    }
+}
+
+impl_op! { DivAssign,
+    fn div_assign(&mut self: Vector2, other: f32) {
+        self.x /= other;
+        self.y /= other;
+    }
+}
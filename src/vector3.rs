@@ -1,9 +1,10 @@
-use std::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
-use std::cmp::{ PartialEq, Eq };
-use std::fmt;
+use core::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
 
-use {ApproxEq, Clamp, Clamp01};
-use consts::{ EPSILON };
+use {ApproxEq, Clamp, Clamp01, MathError, Quaternion, Vector4};
+use consts::{ EPSILON, PI };
+use math;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -22,12 +23,21 @@ impl Vector3 {
 
     pub fn new(x: f32, y: f32, z: f32) -> Vector3 {
         Vector3 {
-            x: x,
-            y: y,
-            z: z
+            x,
+            y,
+            z
         }
     }
     
+    pub fn extend(&self, w: f32) -> Vector4 {
+        Vector4 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w
+        }
+    }
+
     pub fn clamp_magnitude(&self, max_length: f32) -> Vector3 {
         if self.sqr_magnitude() > max_length * max_length {
             return self.normalized() * max_length
@@ -41,13 +51,13 @@ impl Vector3 {
     }
 
     pub fn magnitude(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+        math::sqrt(self.x * self.x + self.y * self.y + self.z * self.z)
     }
 
     pub fn normalize(&mut self) {
         let mag = self.magnitude();
         if mag > EPSILON {
-            *self = *self / mag;
+            *self /= mag;
         }
         else {
             *self = Vector3::ZERO;
@@ -63,6 +73,34 @@ impl Vector3 {
         Vector3::ZERO
     }
 
+    pub fn try_normalized(&self) -> Result<Vector3, MathError> {
+        if !self.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+
+        let mag = self.magnitude();
+        if mag <= EPSILON {
+            return Err(MathError::ZeroLength);
+        }
+
+        // Finite components can still overflow the length, scaling by the largest one brings it back into range
+        if !mag.is_finite() {
+            let scaled = *self / self.abs().max_element();
+            return Ok(scaled / scaled.magnitude());
+        }
+
+        Ok(*self / mag)
+    }
+
+    pub fn normalize_or(&mut self, fallback: Vector3) {
+        *self = self.normalized_or(fallback);
+    }
+
+    // Returns `fallback` where try_normalized would fail
+    pub fn normalized_or(&self, fallback: Vector3) -> Vector3 {
+        self.try_normalized().unwrap_or(fallback)
+    }
+
     pub fn dot(a: Vector3, b: Vector3) -> f32 {
         a.x * b.x + a.y * b.y + a.z * b.z
     }
@@ -80,9 +118,7 @@ impl Vector3 {
     }
 
     pub fn angle(a: Vector3, b: Vector3) -> f32 {
-        Vector3::dot(a.normalized(), b.normalized())
-            .clamp(-1.0, 1.0)
-            .acos()
+        math::acos(Vector3::dot(a.normalized(), b.normalized()).clamp(-1.0, 1.0))
     }
 
     pub fn scale(v: Vector3, other: Vector3) -> Vector3 {
@@ -92,6 +128,95 @@ impl Vector3 {
             z: v.z * other.z
         }
     }
+
+    pub fn min(a: Vector3, b: Vector3) -> Vector3 {
+        Vector3 {
+            x: a.x.min(b.x),
+            y: a.y.min(b.y),
+            z: a.z.min(b.z)
+        }
+    }
+
+    pub fn max(a: Vector3, b: Vector3) -> Vector3 {
+        Vector3 {
+            x: a.x.max(b.x),
+            y: a.y.max(b.y),
+            z: a.z.max(b.z)
+        }
+    }
+
+    pub fn abs(&self) -> Vector3 {
+        Vector3 {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs()
+        }
+    }
+
+    pub fn floor(&self) -> Vector3 {
+        Vector3 {
+            x: math::floor(self.x),
+            y: math::floor(self.y),
+            z: math::floor(self.z)
+        }
+    }
+
+    pub fn ceil(&self) -> Vector3 {
+        Vector3 {
+            x: math::ceil(self.x),
+            y: math::ceil(self.y),
+            z: math::ceil(self.z)
+        }
+    }
+
+    pub fn round(&self) -> Vector3 {
+        Vector3 {
+            x: math::round(self.x),
+            y: math::round(self.y),
+            z: math::round(self.z)
+        }
+    }
+
+    pub fn signum(&self) -> Vector3 {
+        Vector3 {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum()
+        }
+    }
+
+    pub fn recip(&self) -> Vector3 {
+        Vector3 {
+            x: 1.0 / self.x,
+            y: 1.0 / self.y,
+            z: 1.0 / self.z
+        }
+    }
+
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    // Computes self * a + b per component with a single rounding, or two in deterministic mode
+    pub fn mul_add(&self, a: Vector3, b: Vector3) -> Vector3 {
+        Vector3 {
+            x: math::mul_add(self.x, a.x, b.x),
+            y: math::mul_add(self.y, a.y, b.y),
+            z: math::mul_add(self.z, a.z, b.z)
+        }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
     
     pub fn ortho_normalize(a: &mut Vector3, b: &mut Vector3) {
         a.normalize();
@@ -122,6 +247,53 @@ impl Vector3 {
         }
     }
 
+    pub fn move_towards(current: Vector3, target: Vector3, max_distance_delta: f32) -> Vector3 {
+        let delta = target - current;
+        let distance = delta.magnitude();
+        if distance <= max_distance_delta || distance < EPSILON {
+            return target;
+        }
+
+        current + delta / distance * max_distance_delta
+    }
+
+    pub fn rotate_towards(current: Vector3, target: Vector3, max_radians_delta: f32, max_magnitude_delta: f32) -> Vector3 {
+        let current_mag = current.magnitude();
+        let target_mag = target.magnitude();
+        if current_mag < EPSILON || target_mag < EPSILON {
+            return Vector3::move_towards(current, target, max_magnitude_delta);
+        }
+
+        let current_dir = current / current_mag;
+        let target_dir = target / target_mag;
+        let angle = Vector3::angle(current_dir, target_dir);
+
+        let direction = if angle <= max_radians_delta {
+            target_dir
+        }
+        else {
+            let mut axis = Vector3::cross(current_dir, target_dir);
+            if angle > PI - EPSILON || axis.sqr_magnitude() < EPSILON {
+                axis = Vector3::cross(current_dir, Vector3::UP);
+                if axis.sqr_magnitude() < EPSILON {
+                    axis = Vector3::cross(current_dir, Vector3::RIGHT);
+                }
+            }
+
+            Quaternion::from_angle_axis(max_radians_delta, axis.normalized()) * current_dir
+        };
+
+        let delta_mag = target_mag - current_mag;
+        let magnitude = if delta_mag.abs() <= max_magnitude_delta {
+            target_mag
+        }
+        else {
+            current_mag + max_magnitude_delta * delta_mag.signum()
+        };
+
+        direction * magnitude
+    }
+
     pub fn project(v: Vector3, normal: Vector3) -> Vector3 {
         let dot = Vector3::dot(normal, normal);
         if dot < EPSILON {
@@ -148,6 +320,19 @@ impl Vector3 {
     }
 }
 
+impl Clamp for Vector3 {
+    fn clamp(self, min: Vector3, max: Vector3) -> Vector3 {
+        Vector3 {
+            x: Clamp::clamp(self.x, min.x, max.x),
+            y: Clamp::clamp(self.y, min.y, max.y),
+            z: Clamp::clamp(self.z, min.z, max.z)
+        }
+    }
+}
+
+// Conversions
+impl_array_conversions!(Vector3, 3, (f32, f32, f32), x: 0, y: 1, z: 2);
+
 // Formatting
 impl fmt::Debug for Vector3 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -257,6 +442,16 @@ impl_op! { Div,
     }
 }
 
+impl_op! { Div,
+    fn div(self: Vector3, other: Vector3) -> Vector3 {
+        Vector3 {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z
+        }
+    }
+}
+
 impl_op! { Neg,
     fn neg(self: Vector3) -> Vector3 {
         Vector3 {
@@ -0,0 +1,375 @@
+use core::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
+use core::fmt;
+
+use { ApproxEq, Mask4, Mask8, Vector3 };
+use consts::{ EPSILON };
+use math;
+
+// Structure of arrays Vector3 with one lane per element. Every operation is a plain loop over the lanes so the
+// compiler can turn it into vector instructions, lane i behaves exactly like the matching Vector3 operation
+macro_rules! impl_vector3_wide {
+    ($t:ident, $mask:ident, $n:expr) => {
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        pub struct $t {
+            pub x: [f32; $n],
+            pub y: [f32; $n],
+            pub z: [f32; $n]
+        }
+
+        #[allow(dead_code)]
+        impl $t {
+            pub const LANES: usize = $n;
+            pub const ZERO: $t = $t { x: [0.0; $n], y: [0.0; $n], z: [0.0; $n] };
+            pub const ONE: $t = $t { x: [1.0; $n], y: [1.0; $n], z: [1.0; $n] };
+
+            pub fn new(x: [f32; $n], y: [f32; $n], z: [f32; $n]) -> $t {
+                $t {
+                    x,
+                    y,
+                    z
+                }
+            }
+
+            pub fn splat(v: Vector3) -> $t {
+                $t {
+                    x: [v.x; $n],
+                    y: [v.y; $n],
+                    z: [v.z; $n]
+                }
+            }
+
+            // Packs the first LANES vectors, panics if the slice is shorter
+            pub fn from_slice(slice: &[Vector3]) -> $t {
+                let slice = &slice[..$n];
+
+                let mut out = $t::ZERO;
+                for (i, v) in slice.iter().enumerate() {
+                    out.x[i] = v.x;
+                    out.y[i] = v.y;
+                    out.z[i] = v.z;
+                }
+
+                out
+            }
+
+            // Packs up to LANES vectors and fills the remaining lanes with `fill`
+            pub fn from_slice_or(slice: &[Vector3], fill: Vector3) -> $t {
+                let mut out = $t::splat(fill);
+                for (i, v) in slice.iter().take($n).enumerate() {
+                    out.x[i] = v.x;
+                    out.y[i] = v.y;
+                    out.z[i] = v.z;
+                }
+
+                out
+            }
+
+            // Unpacks into the start of `out`, writing at most LANES vectors
+            pub fn write_to_slice(&self, out: &mut [Vector3]) {
+                for (i, v) in out.iter_mut().take($n).enumerate() {
+                    *v = self.lane(i);
+                }
+            }
+
+            pub fn lane(&self, index: usize) -> Vector3 {
+                Vector3::new(self.x[index], self.y[index], self.z[index])
+            }
+
+            pub fn set_lane(&mut self, index: usize, v: Vector3) {
+                self.x[index] = v.x;
+                self.y[index] = v.y;
+                self.z[index] = v.z;
+            }
+
+            pub fn sqr_magnitude(&self) -> [f32; $n] {
+                $t::dot(*self, *self)
+            }
+
+            pub fn magnitude(&self) -> [f32; $n] {
+                let mut out = self.sqr_magnitude();
+                for lane in out.iter_mut() {
+                    *lane = math::sqrt(*lane);
+                }
+
+                out
+            }
+
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+
+            // Lanes too short to normalize become zero, like Vector3::normalized
+            pub fn normalized(&self) -> $t {
+                let mag = self.magnitude();
+
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    if mag[i] > EPSILON {
+                        out.x[i] = self.x[i] / mag[i];
+                        out.y[i] = self.y[i] / mag[i];
+                        out.z[i] = self.z[i] / mag[i];
+                    }
+                }
+
+                out
+            }
+
+            pub fn dot(a: $t, b: $t) -> [f32; $n] {
+                let mut out = [0.0; $n];
+                for i in 0..$n {
+                    out[i] = a.x[i] * b.x[i] + a.y[i] * b.y[i] + a.z[i] * b.z[i];
+                }
+
+                out
+            }
+
+            pub fn cross(a: $t, b: $t) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = a.y[i] * b.z[i] - a.z[i] * b.y[i];
+                    out.y[i] = a.z[i] * b.x[i] - a.x[i] * b.z[i];
+                    out.z[i] = a.x[i] * b.y[i] - a.y[i] * b.x[i];
+                }
+
+                out
+            }
+
+            pub fn distance(a: $t, b: $t) -> [f32; $n] {
+                (a - b).magnitude()
+            }
+
+            pub fn scale(a: $t, b: $t) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = a.x[i] * b.x[i];
+                    out.y[i] = a.y[i] * b.y[i];
+                    out.z[i] = a.z[i] * b.z[i];
+                }
+
+                out
+            }
+
+            pub fn min(a: $t, b: $t) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = a.x[i].min(b.x[i]);
+                    out.y[i] = a.y[i].min(b.y[i]);
+                    out.z[i] = a.z[i].min(b.z[i]);
+                }
+
+                out
+            }
+
+            pub fn max(a: $t, b: $t) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = a.x[i].max(b.x[i]);
+                    out.y[i] = a.y[i].max(b.y[i]);
+                    out.z[i] = a.z[i].max(b.z[i]);
+                }
+
+                out
+            }
+
+            pub fn lerp_unclamped(start: $t, end: $t, t: f32) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = start.x[i] + (end.x[i] - start.x[i]) * t;
+                    out.y[i] = start.y[i] + (end.y[i] - start.y[i]) * t;
+                    out.z[i] = start.z[i] + (end.z[i] - start.z[i]) * t;
+                }
+
+                out
+            }
+
+            // Takes lane i from `a` where the mask is set and from `b` otherwise
+            pub fn select(mask: $mask, a: $t, b: $t) -> $t {
+                $t {
+                    x: $mask::select(mask, a.x, b.x),
+                    y: $mask::select(mask, a.y, b.y),
+                    z: $mask::select(mask, a.z, b.z)
+                }
+            }
+
+            pub fn horizontal_sum(&self) -> Vector3 {
+                let mut out = Vector3::ZERO;
+                for i in 0..$n {
+                    out.x += self.x[i];
+                    out.y += self.y[i];
+                    out.z += self.z[i];
+                }
+
+                out
+            }
+
+            pub fn horizontal_min(&self) -> Vector3 {
+                let mut out = self.lane(0);
+                for i in 1..$n {
+                    out = Vector3::min(out, self.lane(i));
+                }
+
+                out
+            }
+
+            pub fn horizontal_max(&self) -> Vector3 {
+                let mut out = self.lane(0);
+                for i in 1..$n {
+                    out = Vector3::max(out, self.lane(i));
+                }
+
+                out
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "(x: {:?}, y: {:?}, z: {:?})", self.x, self.y, self.z)
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "(x: {:?}, y: {:?}, z: {:?})", self.x, self.y, self.z)
+            }
+        }
+
+        // Lane-wise approximate equality like Vector3
+        impl PartialEq for $t {
+            fn eq(&self, other: &$t) -> bool {
+                self.approx_eq(*other)
+            }
+        }
+
+        impl_op! { ApproxEq,
+            fn approx_eq(self: $t, other: $t) -> bool {
+                (0..$n).all(|i| self.x[i].approx_eq(other.x[i]) && self.y[i].approx_eq(other.y[i]) && self.z[i].approx_eq(other.z[i]))
+            }
+        }
+
+        impl_op! { Add,
+            fn add(self: $t, other: $t) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = self.x[i] + other.x[i];
+                    out.y[i] = self.y[i] + other.y[i];
+                    out.z[i] = self.z[i] + other.z[i];
+                }
+
+                out
+            }
+        }
+
+        impl_op! { Sub,
+            fn sub(self: $t, other: $t) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = self.x[i] - other.x[i];
+                    out.y[i] = self.y[i] - other.y[i];
+                    out.z[i] = self.z[i] - other.z[i];
+                }
+
+                out
+            }
+        }
+
+        impl_op! { Mul,
+            fn mul(self: $t, other: $t) -> $t {
+                $t::scale(self, other)
+            }
+        }
+
+        impl_op! { Mul,
+            fn mul(self: $t, other: f32) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = self.x[i] * other;
+                    out.y[i] = self.y[i] * other;
+                    out.z[i] = self.z[i] * other;
+                }
+
+                out
+            }
+        }
+
+        // Scales each lane by its own factor
+        impl_op! { Mul,
+            fn mul(self: $t, other: [f32; $n]) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = self.x[i] * other[i];
+                    out.y[i] = self.y[i] * other[i];
+                    out.z[i] = self.z[i] * other[i];
+                }
+
+                out
+            }
+        }
+
+        impl_op! { Div,
+            fn div(self: $t, other: f32) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = self.x[i] / other;
+                    out.y[i] = self.y[i] / other;
+                    out.z[i] = self.z[i] / other;
+                }
+
+                out
+            }
+        }
+
+        impl_op! { Div,
+            fn div(self: $t, other: [f32; $n]) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = self.x[i] / other[i];
+                    out.y[i] = self.y[i] / other[i];
+                    out.z[i] = self.z[i] / other[i];
+                }
+
+                out
+            }
+        }
+
+        impl_op! { Neg,
+            fn neg(self: $t) -> $t {
+                let mut out = $t::ZERO;
+                for i in 0..$n {
+                    out.x[i] = -self.x[i];
+                    out.y[i] = -self.y[i];
+                    out.z[i] = -self.z[i];
+                }
+
+                out
+            }
+        }
+
+        impl_op! { AddAssign,
+            fn add_assign(&mut self: $t, other: $t) {
+                *self = *self + other;
+            }
+        }
+
+        impl_op! { SubAssign,
+            fn sub_assign(&mut self: $t, other: $t) {
+                *self = *self - other;
+            }
+        }
+
+        impl_op! { MulAssign,
+            fn mul_assign(&mut self: $t, other: f32) {
+                *self = *self * other;
+            }
+        }
+
+        impl_op! { DivAssign,
+            fn div_assign(&mut self: $t, other: f32) {
+                *self = *self / other;
+            }
+        }
+    };
+}
+
+impl_vector3_wide!(Vector3x4, Mask4, 4);
+impl_vector3_wide!(Vector3x8, Mask8, 8);
@@ -1,9 +1,12 @@
-use std::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
-use std::cmp::{ PartialEq, Eq };
-use std::fmt;
+use core::ops::{ Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign };
+use core::cmp::{ PartialEq, Eq };
+use core::fmt;
 
-use {ApproxEq, Clamp01};
+use {ApproxEq, Clamp, Clamp01, MathError, Vector3};
 use consts::{ EPSILON };
+use math;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+use simd;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -20,13 +23,38 @@ impl Vector4 {
 
     pub fn new(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
         Vector4 {
-            x: x,
-            y: y,
-            z: z,
-            w: w
+            x,
+            y,
+            z,
+            w
         }
     }
     
+    pub fn from_point(point: Vector3) -> Vector4 {
+        point.extend(1.0)
+    }
+
+    pub fn from_direction(direction: Vector3) -> Vector4 {
+        direction.extend(0.0)
+    }
+
+    pub fn truncate(&self) -> Vector3 {
+        Vector3 {
+            x: self.x,
+            y: self.y,
+            z: self.z
+        }
+    }
+
+    // Returns None for points at infinity where w is too close to zero to divide by
+    pub fn perspective_divide(&self) -> Option<Vector3> {
+        if self.w.abs() < EPSILON {
+            return None;
+        }
+
+        Some(self.truncate() / self.w)
+    }
+
     pub fn clamp_magnitude(&self, max_length: f32) -> Vector4 {
         if self.sqr_magnitude() > max_length * max_length {
             return self.normalized() * max_length
@@ -40,13 +68,13 @@ impl Vector4 {
     }
 
     pub fn magnitude(&self) -> f32 {
-        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
+        math::sqrt(self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w)
     }
 
     pub fn normalize(&mut self) {
         let mag = self.magnitude();
         if mag > EPSILON {
-            *self = *self / mag;
+            *self /= mag;
         }
         else {
             *self = Vector4::ZERO;
@@ -62,9 +90,43 @@ impl Vector4 {
         Vector4::ZERO
     }
 
+    pub fn try_normalized(&self) -> Result<Vector4, MathError> {
+        if !self.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+
+        let mag = self.magnitude();
+        if mag <= EPSILON {
+            return Err(MathError::ZeroLength);
+        }
+
+        // Finite components can still overflow the length, scaling by the largest one brings it back into range
+        if !mag.is_finite() {
+            let scaled = *self / self.abs().max_element();
+            return Ok(scaled / scaled.magnitude());
+        }
+
+        Ok(*self / mag)
+    }
+
+    pub fn normalize_or(&mut self, fallback: Vector4) {
+        *self = self.normalized_or(fallback);
+    }
+
+    // Returns `fallback` where try_normalized would fail
+    pub fn normalized_or(&self, fallback: Vector4) -> Vector4 {
+        self.try_normalized().unwrap_or(fallback)
+    }
+
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64", target_feature = "sse4.1", not(feature = "deterministic"))))]
     pub fn dot(a: Vector4, b: Vector4) -> f32 {
         a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w
     }
+
+    #[cfg(all(feature = "simd", target_arch = "x86_64", target_feature = "sse4.1", not(feature = "deterministic")))]
+    pub fn dot(a: Vector4, b: Vector4) -> f32 {
+        simd::dot(a, b)
+    }
     
     pub fn distance(a: Vector4, b: Vector4) -> f32 {
         (a - b).magnitude()
@@ -78,6 +140,104 @@ impl Vector4 {
             w: v.w * other.w
         }
     }
+
+    pub fn min(a: Vector4, b: Vector4) -> Vector4 {
+        Vector4 {
+            x: a.x.min(b.x),
+            y: a.y.min(b.y),
+            z: a.z.min(b.z),
+            w: a.w.min(b.w)
+        }
+    }
+
+    pub fn max(a: Vector4, b: Vector4) -> Vector4 {
+        Vector4 {
+            x: a.x.max(b.x),
+            y: a.y.max(b.y),
+            z: a.z.max(b.z),
+            w: a.w.max(b.w)
+        }
+    }
+
+    pub fn abs(&self) -> Vector4 {
+        Vector4 {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+            w: self.w.abs()
+        }
+    }
+
+    pub fn floor(&self) -> Vector4 {
+        Vector4 {
+            x: math::floor(self.x),
+            y: math::floor(self.y),
+            z: math::floor(self.z),
+            w: math::floor(self.w)
+        }
+    }
+
+    pub fn ceil(&self) -> Vector4 {
+        Vector4 {
+            x: math::ceil(self.x),
+            y: math::ceil(self.y),
+            z: math::ceil(self.z),
+            w: math::ceil(self.w)
+        }
+    }
+
+    pub fn round(&self) -> Vector4 {
+        Vector4 {
+            x: math::round(self.x),
+            y: math::round(self.y),
+            z: math::round(self.z),
+            w: math::round(self.w)
+        }
+    }
+
+    pub fn signum(&self) -> Vector4 {
+        Vector4 {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum(),
+            w: self.w.signum()
+        }
+    }
+
+    pub fn recip(&self) -> Vector4 {
+        Vector4 {
+            x: 1.0 / self.x,
+            y: 1.0 / self.y,
+            z: 1.0 / self.z,
+            w: 1.0 / self.w
+        }
+    }
+
+    pub fn min_element(&self) -> f32 {
+        self.x.min(self.y).min(self.z).min(self.w)
+    }
+
+    pub fn max_element(&self) -> f32 {
+        self.x.max(self.y).max(self.z).max(self.w)
+    }
+
+    // Computes self * a + b per component with a single rounding, or two in deterministic mode
+    pub fn mul_add(&self, a: Vector4, b: Vector4) -> Vector4 {
+        Vector4 {
+            x: math::mul_add(self.x, a.x, b.x),
+            y: math::mul_add(self.y, a.y, b.y),
+            z: math::mul_add(self.z, a.z, b.z),
+            w: math::mul_add(self.w, a.w, b.w)
+        }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
     
     pub fn lerp(start: Vector4, end: Vector4, t: f32) -> Vector4 {
         let alpha = t.clamp01();
@@ -110,6 +270,20 @@ impl Vector4 {
     }
 }
 
+impl Clamp for Vector4 {
+    fn clamp(self, min: Vector4, max: Vector4) -> Vector4 {
+        Vector4 {
+            x: Clamp::clamp(self.x, min.x, max.x),
+            y: Clamp::clamp(self.y, min.y, max.y),
+            z: Clamp::clamp(self.z, min.z, max.z),
+            w: Clamp::clamp(self.w, min.w, max.w)
+        }
+    }
+}
+
+// Conversions
+impl_array_conversions!(Vector4, 4, (f32, f32, f32, f32), x: 0, y: 1, z: 2, w: 3);
+
 // Formatting
 impl fmt::Debug for Vector4 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -139,6 +313,7 @@ impl_op! { ApproxEq,
 }
 
 // Ops
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
 impl_op! { Add,
     fn add(self: Vector4, other: Vector4) -> Vector4 {
         Vector4 {
@@ -150,6 +325,13 @@ impl_op! { Add,
     }
 }
 
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl_op! { Add,
+    fn add(self: Vector4, other: Vector4) -> Vector4 {
+        simd::add(self, other)
+    }
+}
+
 impl_op! { Add,
     fn add(self: Vector4, other: f32) -> Vector4 {
         Vector4 {
@@ -161,6 +343,7 @@ impl_op! { Add,
     }
 }
 
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
 impl_op! { Sub,
     fn sub(self: Vector4, other: Vector4) -> Vector4 {
         Vector4 {
@@ -172,6 +355,13 @@ impl_op! { Sub,
     }
 }
 
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl_op! { Sub,
+    fn sub(self: Vector4, other: Vector4) -> Vector4 {
+        simd::sub(self, other)
+    }
+}
+
 impl_op! { Sub,
     fn sub(self: Vector4, other: f32) -> Vector4 {
         Vector4 {
@@ -183,6 +373,7 @@ impl_op! { Sub,
     }
 }
 
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
 impl_op! { Mul,
     fn mul(self: Vector4, other: Vector4) -> Vector4 {
         Vector4 {
@@ -194,6 +385,14 @@ impl_op! { Mul,
     }
 }
 
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl_op! { Mul,
+    fn mul(self: Vector4, other: Vector4) -> Vector4 {
+        simd::mul(self, other)
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
 impl_op! { Mul,
     fn mul(self: Vector4, other: f32) -> Vector4 {
         Vector4 {
@@ -205,6 +404,13 @@ impl_op! { Mul,
     }
 }
 
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl_op! { Mul,
+    fn mul(self: Vector4, other: f32) -> Vector4 {
+        simd::scale(self, other)
+    }
+}
+
 impl_op! { Mul,
     fn mul(self: f32, other: Vector4) -> Vector4 {
         Vector4 {
@@ -216,6 +422,7 @@ impl_op! { Mul,
     }
 }
 
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
 impl_op! { Div,
     fn div(self: Vector4, other: f32) -> Vector4 {
         Vector4 {
@@ -227,6 +434,32 @@ impl_op! { Div,
     }
 }
 
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl_op! { Div,
+    fn div(self: Vector4, other: f32) -> Vector4 {
+        simd::div_scalar(self, other)
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+impl_op! { Div,
+    fn div(self: Vector4, other: Vector4) -> Vector4 {
+        Vector4 {
+            x: self.x / other.x,
+            y: self.y / other.y,
+            z: self.z / other.z,
+            w: self.w / other.w
+        }
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+impl_op! { Div,
+    fn div(self: Vector4, other: Vector4) -> Vector4 {
+        simd::div(self, other)
+    }
+}
+
 impl_op! { Neg,
     fn neg(self: Vector4) -> Vector4 {
         Vector4 {
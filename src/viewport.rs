@@ -0,0 +1,120 @@
+use { Vector2, Vector3, Vector4, Matrix4x4, Ray };
+use consts::{ EPSILON };
+
+// Range of normalized device depth produced by the projection matrix
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepthRange {
+    ZeroToOne,
+    NegativeOneToOne
+}
+
+// Which corner pixel (0, 0) refers to, top left for y down window coordinates
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ViewportOrigin {
+    TopLeft,
+    BottomLeft
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Viewport {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub min_depth: f32,
+    pub max_depth: f32,
+    pub depth_range: DepthRange,
+    pub origin: ViewportOrigin
+}
+
+impl Viewport {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Viewport {
+        Viewport {
+            x,
+            y,
+            width,
+            height,
+            min_depth: 0.0,
+            max_depth: 1.0,
+            depth_range: DepthRange::ZeroToOne,
+            origin: ViewportOrigin::TopLeft
+        }
+    }
+
+    // Maps a world space point to pixel coordinates with depth in [min_depth, max_depth]. Returns None for
+    // points on or behind the camera plane since they have no meaningful screen position
+    pub fn project(&self, point: Vector3, view_projection: Matrix4x4) -> Option<Vector3> {
+        let clip = view_projection * Vector4::from_point(point);
+        if clip.w < EPSILON {
+            return None;
+        }
+
+        clip.perspective_divide().map(|ndc| self.ndc_to_screen(ndc))
+    }
+
+    // Maps pixel coordinates and viewport depth back to a world space point. Like project, returns None when
+    // there is no such point: a singular view projection, w too close to zero or a result that is not finite
+    pub fn unproject(&self, screen: Vector3, view_projection: Matrix4x4) -> Option<Vector3> {
+        self.unproject_with_inverse(screen, view_projection.try_inverse().ok()?)
+    }
+
+    pub fn screen_point_to_ray(&self, screen: Vector2, view_projection: Matrix4x4) -> Option<Ray> {
+        let inverse = view_projection.try_inverse().ok()?;
+
+        let near = self.unproject_with_inverse(Vector3::new(screen.x, screen.y, self.min_depth), inverse)?;
+        let far = self.unproject_with_inverse(Vector3::new(screen.x, screen.y, self.max_depth), inverse)?;
+
+        let direction = far - near;
+        if !direction.is_finite() || direction.sqr_magnitude() < EPSILON {
+            return None;
+        }
+
+        Some(Ray::new(near, direction))
+    }
+
+    fn unproject_with_inverse(&self, screen: Vector3, inverse_view_projection: Matrix4x4) -> Option<Vector3> {
+        let ndc = self.screen_to_ndc(screen);
+        let world = inverse_view_projection * Vector4::from_point(ndc);
+
+        world.perspective_divide().filter(|point| point.is_finite())
+    }
+
+    fn ndc_to_screen(&self, ndc: Vector3) -> Vector3 {
+        let x = self.x + (ndc.x + 1.0) * 0.5 * self.width;
+        let y = match self.origin {
+            ViewportOrigin::TopLeft => self.y + (1.0 - ndc.y) * 0.5 * self.height,
+            ViewportOrigin::BottomLeft => self.y + (ndc.y + 1.0) * 0.5 * self.height
+        };
+
+        let depth = match self.depth_range {
+            DepthRange::ZeroToOne => ndc.z,
+            DepthRange::NegativeOneToOne => (ndc.z + 1.0) * 0.5
+        };
+
+        Vector3 {
+            x,
+            y,
+            z: self.min_depth + depth * (self.max_depth - self.min_depth)
+        }
+    }
+
+    fn screen_to_ndc(&self, screen: Vector3) -> Vector3 {
+        let x = (screen.x - self.x) / self.width * 2.0 - 1.0;
+        let y = match self.origin {
+            ViewportOrigin::TopLeft => 1.0 - (screen.y - self.y) / self.height * 2.0,
+            ViewportOrigin::BottomLeft => (screen.y - self.y) / self.height * 2.0 - 1.0
+        };
+
+        let depth = (screen.z - self.min_depth) / (self.max_depth - self.min_depth);
+        let z = match self.depth_range {
+            DepthRange::ZeroToOne => depth,
+            DepthRange::NegativeOneToOne => depth * 2.0 - 1.0
+        };
+
+        Vector3 {
+            x,
+            y,
+            z
+        }
+    }
+}